@@ -31,6 +31,38 @@ fn bench_add_edge(b: &mut test::Bencher) {
     })
 }
 
+/// Build a graph of 1000 nodes with a million edges, growing the vectors as needed.
+#[bench]
+fn bench_build_million_edges(b: &mut test::Bencher) {
+    b.iter(|| {
+        let mut og = Graph::<(), ()>::new();
+        build_million_edges(&mut og);
+        og
+    })
+}
+
+/// Build the same graph, with the nodes and edges reserved beforehand.
+#[bench]
+fn bench_build_million_edges_reserved(b: &mut test::Bencher) {
+    b.iter(|| {
+        let mut og = Graph::<(), ()>::with_capacity(1000, 0);
+        og.reserve_edges(1_000_000);
+        build_million_edges(&mut og);
+        og
+    })
+}
+
+fn build_million_edges(og: &mut Graph<(), ()>) {
+    for _ in 0..1000 {
+        og.add_node(());
+    }
+    for a in og.node_indices() {
+        for b in og.node_indices() {
+            og.add_edge(a, b, ());
+        }
+    }
+}
+
 #[bench]
 fn bench_remove(b: &mut test::Bencher) {
     // removal is very slow in a big graph.
@@ -10,6 +10,7 @@ mod common;
 use common::*;
 
 use petgraph::algo::{connected_components, is_cyclic_undirected};
+use petgraph::unionfind::UnionFind;
 
 #[bench]
 fn connected_components_praust_undir_bench(bench: &mut Bencher) {
@@ -106,3 +107,30 @@ fn is_cyclic_undirected_petersen_dir_bench(bench: &mut Bencher) {
 
     bench.iter(|| (is_cyclic_undirected(&a), is_cyclic_undirected(&b)));
 }
+
+fn random_edges(n: u32, m: usize) -> Vec<(u32, u32)> {
+    // a fixed linear congruential generator, so each run uses the same edges
+    let mut state = 0x2545_f491u64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((state >> 33) % u64::from(n)) as u32
+    };
+    (0..m).map(|_| (next(), next())).collect()
+}
+
+#[bench]
+fn union_find_from_edges_bench(bench: &mut Bencher) {
+    let edges = random_edges(100_000, 400_000);
+
+    bench.iter(|| UnionFind::from_edges(100_000, edges.iter().cloned()));
+}
+
+#[cfg(feature = "rayon")]
+#[bench]
+fn union_find_par_from_edges_bench(bench: &mut Bencher) {
+    let edges = random_edges(100_000, 400_000);
+
+    bench.iter(|| UnionFind::par_from_edges(100_000, &edges));
+}
@@ -17,6 +17,7 @@ use itertools::{repeat_n, Itertools};
 
 use petgraph::graph::{edge_index, node_index, IndexType};
 use petgraph::prelude::*;
+use petgraph::unionfind::UnionFind;
 use petgraph::visit::EdgeRef;
 use petgraph::visit::IntoEdgeReferences;
 use petgraph::visit::NodeIndexable;
@@ -564,3 +565,63 @@ fn json_graphmap_struct() {
         (1, 2.)
     );
 }
+
+fn make_union_find() -> UnionFind<u32> {
+    let mut u = UnionFind::new(10);
+    u.union(0, 1);
+    u.union(1, 2);
+    u.union(4, 7);
+    u.union(8, 9);
+    u.union(9, 4);
+    u
+}
+
+fn assert_union_find_eq(u: &UnionFind<u32>, v: &UnionFind<u32>) {
+    assert_eq!(u.len(), v.len());
+    for i in 0..u.len() as u32 {
+        for j in 0..u.len() as u32 {
+            assert_eq!(u.equiv(i, j), v.equiv(i, j));
+        }
+    }
+}
+
+#[test]
+fn json_union_find() {
+    let mut u = make_union_find();
+    let mut v: UnionFind<u32> = rejson!(&u);
+    assert_union_find_eq(&u, &v);
+    for i in 0..u.len() as u32 {
+        assert_eq!(u.set_size(i), v.set_size(i));
+    }
+}
+
+#[test]
+fn bincode_union_find() {
+    let u = make_union_find();
+    let v: UnionFind<u32> = decode!(&encode!(&u));
+    assert_union_find_eq(&u, &v);
+}
+
+#[test]
+#[should_panic(expected = "does not match parent length")]
+fn from_json_union_find_length_mismatch() {
+    let _: UnionFind<u32> = fromjson!(r#"{"parent":[0,0,2],"rank":[1,0]}"#);
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn from_json_union_find_parent_oob() {
+    let _: UnionFind<u32> = fromjson!(r#"{"parent":[0,3,2],"rank":[0,0,0]}"#);
+}
+
+#[test]
+#[should_panic(expected = "parent cycle")]
+fn from_json_union_find_parent_cycle() {
+    let _: UnionFind<u32> = fromjson!(r#"{"parent":[0,2,3,1],"rank":[0,0,0,0]}"#);
+}
+
+#[test]
+#[should_panic(expected = "too large")]
+fn from_json_union_find_rank_too_large() {
+    let _: UnionFind<u32> = fromjson!(r#"{"parent":[0,0,2],"rank":[2,0,0]}"#);
+}
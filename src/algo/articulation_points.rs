@@ -0,0 +1,92 @@
+//! Articulation points (cut vertices).
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+
+use super::undirected_edges;
+
+/// \[Generic\] Find the *articulation points* of a graph: the nodes whose removal, together
+/// with their edges, increases the number of connected components.
+///
+/// The input graph is treated as if undirected.
+///
+/// Using the lowlink algorithm of Hopcroft and Tarjan, with runtime **O(|V| + |E|)**:
+/// a depth-first search records when each node is discovered and the earliest discovered
+/// node reachable from its subtree with a single back edge. A node other than the root of
+/// the search is an articulation point if one of its children cannot reach past it, and the
+/// root is one if it has two or more children. Each connected component is searched in turn.
+///
+/// Self loops and parallel edges do not change the result.
+///
+/// Return the articulation points in the order of `g.node_identifiers()`.
+///
+/// This implementation is iterative.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::articulation_points;
+/// use petgraph::graph::{node_index, UnGraph};
+///
+/// // two triangles that share node 2
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// assert_eq!(articulation_points(&g), vec![node_index(2)]);
+/// ```
+pub fn articulation_points<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let ix = |n| g.to_index(n);
+    // Discovery times start at 1, so that 0 marks undiscovered nodes
+    let mut discovered = vec![0; g.node_bound()];
+    let mut low = vec![0; g.node_bound()];
+    let mut is_cut = vec![false; g.node_bound()];
+    let mut time = 0;
+    let mut stack = Vec::new();
+
+    for root in g.node_identifiers() {
+        if discovered[ix(root)] != 0 {
+            continue;
+        }
+        time += 1;
+        discovered[ix(root)] = time;
+        low[ix(root)] = time;
+        let mut root_children = 0;
+        stack.push((root, None, undirected_edges(g, root)));
+
+        while let Some(&mut (u, parent_edge, ref mut edges)) = stack.last_mut() {
+            if let Some(edge) = edges.next() {
+                // Going back along the tree edge to the parent is not a back edge
+                if Some(edge.id()) == parent_edge {
+                    continue;
+                }
+                let v = if edge.source() == u {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if discovered[ix(v)] == 0 {
+                    time += 1;
+                    discovered[ix(v)] = time;
+                    low[ix(v)] = time;
+                    stack.push((v, Some(edge.id()), undirected_edges(g, v)));
+                } else {
+                    low[ix(u)] = low[ix(u)].min(discovered[ix(v)]);
+                }
+            } else {
+                stack.pop();
+                if let Some(&(parent, _, _)) = stack.last() {
+                    low[ix(parent)] = low[ix(parent)].min(low[ix(u)]);
+                    if parent == root {
+                        root_children += 1;
+                    } else if low[ix(u)] >= discovered[ix(parent)] {
+                        is_cut[ix(parent)] = true;
+                    }
+                }
+            }
+        }
+        if root_children >= 2 {
+            is_cut[ix(root)] = true;
+        }
+    }
+
+    g.node_identifiers().filter(|&n| is_cut[ix(n)]).collect()
+}
@@ -2,13 +2,18 @@
 
 use crate::prelude::*;
 
-use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, VisitMap, Visitable};
+use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, Visitable};
 
 use super::{FloatMeasure, NegativeCycle};
 
+/// The shortest paths found by `bellman_ford`, indexed by the graph's node indices.
 #[derive(Debug, Clone)]
 pub struct Paths<NodeId, EdgeWeight> {
+    /// The cost of the shortest path from the source to each node, or infinity
+    /// if the node is not reachable.
     pub distances: Vec<EdgeWeight>,
+    /// The node before each node on its shortest path, or `None` for the source
+    /// and for nodes that are not reachable.
     pub predecessors: Vec<Option<NodeId>>,
 }
 
@@ -114,6 +119,12 @@ where
 /// [Bellman–Ford algorithm][bf]. If no negative cycle is found the function will return `None`.
 ///
 /// If a negative cycle is found from source, return one vec with a path of `NodeId`s.
+/// Each node of the path has an edge to the next one, and the last node has an edge
+/// back to the first.
+///
+/// The cycle is extracted from an edge that can still be relaxed after |V| - 1 rounds:
+/// walking |V| times back the predecessors of its target lands on the cycle, which is
+/// then traced out.
 ///
 /// The time complexity of this algorithm should be the same as the Bellman-Ford (O(|V|·|E|)).
 ///
@@ -150,7 +161,7 @@ where
     let mut path = Vec::<G::NodeId>::new();
 
     // Step 1: initialize and relax
-    let (distance, predecessor) = bellman_ford_initialize_relax(g, source);
+    let (distance, mut predecessor) = bellman_ford_initialize_relax(g, source);
 
     // Step 2: Check for negative weight cycle
     'outer: for i in g.node_identifiers() {
@@ -158,38 +169,33 @@ where
             let j = edge.target();
             let w = *edge.weight();
             if distance[ix(i)] + w < distance[ix(j)] {
-                // Step 3: negative cycle found
-                let start = j;
-                let mut node = start;
-                let mut visited = g.visit_map();
-                // Go backward in the predecessor chain
+                // Step 3: negative cycle found.
+                // Relaxing the edge once more puts a cycle on the predecessor chain of `j`:
+                // an acyclic chain would be a simple path from `source`, and after |V| - 1
+                // rounds, the distance of `j` is already at most the weight of any such path.
+                predecessor[ix(j)] = Some(i);
+                // Go backward |V| times in the predecessor chain, to land on the cycle
+                let mut node = j;
+                for _ in 0..g.node_count() {
+                    node = match predecessor[ix(node)] {
+                        Some(predecessor_node) => predecessor_node,
+                        None => break 'outer,
+                    };
+                }
+                // Trace the cycle, backward
+                let start = node;
                 loop {
-                    let ancestor = match predecessor[ix(node)] {
+                    path.push(node);
+                    node = match predecessor[ix(node)] {
                         Some(predecessor_node) => predecessor_node,
-                        None => node, // no predecessor, self cycle
+                        None => {
+                            path.clear();
+                            break 'outer;
+                        }
                     };
-                    // We have only 2 ways to find the cycle and break the loop:
-                    // 1. start is reached
-                    if ancestor == start {
-                        path.push(ancestor);
+                    if node == start {
                         break;
                     }
-                    // 2. some node was reached twice
-                    else if visited.is_visited(&ancestor) {
-                        // Drop any node in path that is before the first ancestor
-                        let pos = path
-                            .iter()
-                            .position(|&p| p == ancestor)
-                            .expect("we should always have a position");
-                        path = path[pos..path.len()].to_vec();
-
-                        break;
-                    }
-
-                    // None of the above, some middle path node
-                    path.push(ancestor);
-                    visited.visit(ancestor);
-                    node = ancestor;
                 }
                 // We are done here
                 break 'outer;
@@ -0,0 +1,105 @@
+//! Betweenness centrality.
+
+use std::collections::VecDeque;
+
+use crate::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute the *betweenness centrality* of every node: the number of shortest
+/// paths between two other nodes that go through it, where each pair of nodes counts as one,
+/// shared between all of its shortest paths.
+///
+/// The edges are unweighted, and the length of a path is its number of edges. In a directed
+/// graph, paths follow the direction of the edges, and the pairs of nodes are ordered: the
+/// paths from `a` to `b` and from `b` to `a` both count. In an undirected graph, each pair of
+/// nodes counts once.
+///
+/// If `normalized` is `true`, the centralities are divided by the number of pairs of other
+/// nodes: **(n - 1)(n - 2)** in a directed graph, and **(n - 1)(n - 2) / 2** in an undirected
+/// graph, where **n** is the number of nodes, so that they are between 0 and 1.
+///
+/// Self loops are ignored. Parallel edges are all counted, so two parallel edges make two
+/// shortest paths.
+///
+/// Using [Brandes' algorithm][1], with runtime **O(|V||E|)**: for each node, a breadth-first
+/// search counts the shortest paths to every other node, and the dependencies on each node are
+/// accumulated in the reverse order of the search.
+///
+/// Return a vector indexed by `g.to_index(node)`, of length `g.node_bound()`.
+/// Indices that belong to no node have centrality 0.
+///
+/// [1]: https://doi.org/10.1080/0022250X.2001.9990249
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::betweenness_centrality;
+/// use petgraph::graph::UnGraph;
+///
+/// // a path: 0 - 1 - 2 - 3
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(betweenness_centrality(&g, false), vec![0., 2., 2., 0.]);
+/// assert_eq!(betweenness_centrality(&g, true), vec![0., 2. / 3., 2. / 3., 0.]);
+/// ```
+pub fn betweenness_centrality<G>(g: G, normalized: bool) -> Vec<f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    const UNREACHED: usize = std::usize::MAX;
+
+    let ix = |n| g.to_index(n);
+    let mut centrality = vec![0.; g.node_bound()];
+    // The number of shortest paths from the current source
+    let mut paths = vec![0.; g.node_bound()];
+    let mut distance = vec![UNREACHED; g.node_bound()];
+    let mut predecessors = vec![Vec::new(); g.node_bound()];
+    let mut dependency = vec![0.; g.node_bound()];
+    let mut queue = VecDeque::new();
+    let mut order = Vec::new();
+
+    for source in g.node_identifiers() {
+        paths[ix(source)] = 1.;
+        distance[ix(source)] = 0;
+        queue.push_back(source);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            for w in g.neighbors(v) {
+                if distance[ix(w)] == UNREACHED {
+                    distance[ix(w)] = distance[ix(v)] + 1;
+                    queue.push_back(w);
+                }
+                if distance[ix(w)] == distance[ix(v)] + 1 {
+                    paths[ix(w)] += paths[ix(v)];
+                    predecessors[ix(w)].push(v);
+                }
+            }
+        }
+
+        // Accumulate the dependencies, farthest nodes first, and reset the visited nodes
+        while let Some(w) = order.pop() {
+            for &v in &predecessors[ix(w)] {
+                dependency[ix(v)] += paths[ix(v)] / paths[ix(w)] * (1. + dependency[ix(w)]);
+            }
+            if w != source {
+                centrality[ix(w)] += dependency[ix(w)];
+            }
+            paths[ix(w)] = 0.;
+            distance[ix(w)] = UNREACHED;
+            predecessors[ix(w)].clear();
+            dependency[ix(w)] = 0.;
+        }
+    }
+
+    // An undirected path is found from both of its ends
+    let mut scale = if g.is_directed() { 1. } else { 0.5 };
+    let node_count = g.node_identifiers().count();
+    if normalized && node_count > 2 {
+        let mut pairs = ((node_count - 1) * (node_count - 2)) as f64;
+        if !g.is_directed() {
+            pairs /= 2.;
+        }
+        scale /= pairs;
+    }
+    for c in &mut centrality {
+        *c *= scale;
+    }
+    centrality
+}
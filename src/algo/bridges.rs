@@ -0,0 +1,87 @@
+//! Bridges (cut edges).
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+
+use super::undirected_edges;
+
+/// \[Generic\] Find the *bridges* of a graph: the edges whose removal increases the number
+/// of connected components.
+///
+/// The input graph is treated as if undirected.
+///
+/// Using the lowlink algorithm of Tarjan, with runtime **O(|V| + |E|)**: a depth-first
+/// search records when each node is discovered and the earliest discovered node reachable
+/// from its subtree with a single back edge. The tree edge from `u` to its child `v` is a
+/// bridge if the subtree of `v` cannot reach `u` or an earlier node. Each connected
+/// component is searched in turn.
+///
+/// An edge that has a parallel edge, in either direction, is never a bridge, and neither
+/// is a self loop.
+///
+/// Return the bridges in the order they are found.
+///
+/// This implementation is iterative.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bridges;
+/// use petgraph::graph::{edge_index, UnGraph};
+///
+/// // a triangle with a tail
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// assert_eq!(bridges(&g), vec![edge_index(3)]);
+/// ```
+pub fn bridges<G>(g: G) -> Vec<G::EdgeId>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let ix = |n| g.to_index(n);
+    // Discovery times start at 1, so that 0 marks undiscovered nodes
+    let mut discovered = vec![0; g.node_bound()];
+    let mut low = vec![0; g.node_bound()];
+    let mut bridges = Vec::new();
+    let mut time = 0;
+    let mut stack = Vec::new();
+
+    for root in g.node_identifiers() {
+        if discovered[ix(root)] != 0 {
+            continue;
+        }
+        time += 1;
+        discovered[ix(root)] = time;
+        low[ix(root)] = time;
+        stack.push((root, None, undirected_edges(g, root)));
+
+        while let Some(&mut (u, parent_edge, ref mut edges)) = stack.last_mut() {
+            if let Some(edge) = edges.next() {
+                // Skip the tree edge to the parent, but not the edges parallel to it
+                if Some(edge.id()) == parent_edge {
+                    continue;
+                }
+                let v = if edge.source() == u {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if discovered[ix(v)] == 0 {
+                    time += 1;
+                    discovered[ix(v)] = time;
+                    low[ix(v)] = time;
+                    stack.push((v, Some(edge.id()), undirected_edges(g, v)));
+                } else {
+                    low[ix(u)] = low[ix(u)].min(discovered[ix(v)]);
+                }
+            } else {
+                stack.pop();
+                if let (Some(&(parent, _, _)), Some(tree_edge)) = (stack.last(), parent_edge) {
+                    low[ix(parent)] = low[ix(parent)].min(low[ix(u)]);
+                    if low[ix(u)] > discovered[ix(parent)] {
+                        bridges.push(tree_edge);
+                    }
+                }
+            }
+        }
+    }
+
+    bridges
+}
@@ -0,0 +1,79 @@
+//! Graph coloring.
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+
+use super::undirected_edges;
+
+/// \[Generic\] Compute a *proper coloring* of the nodes of a graph, in which no two adjacent
+/// nodes have the same color, using the Welsh–Powell heuristic.
+///
+/// The input graph is treated as if undirected.
+///
+/// The nodes are colored in decreasing order of degree, ties broken by the order of
+/// `node_identifiers`, and each node gets the smallest color not used by its neighbors
+/// colored before it. The coloring is not always optimal, but it uses at most
+/// **Δ + 1** colors, where **Δ** is the maximum degree of `g`.
+///
+/// A node with a self loop is adjacent to itself, so no coloring of it is proper: self loops
+/// are ignored, and the coloring is proper for all the other edges.
+///
+/// Runtime is **O(|V| log |V| + |E|)**.
+///
+/// Return the colors and the number of colors used, which are numbered from 0. The vector of
+/// colors is indexed by `g.to_index(node)`, of length `g.node_bound()`; indices that belong to
+/// no node have color 0.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::greedy_coloring;
+/// use petgraph::graph::UnGraph;
+///
+/// // a square, with one diagonal: 0 - 1 - 2 - 3 - 0, and 0 - 2
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+/// let (colors, count) = greedy_coloring(&g);
+/// assert_eq!(count, 3);
+/// for edge in g.raw_edges() {
+///     assert_ne!(colors[edge.source().index()], colors[edge.target().index()]);
+/// }
+/// ```
+pub fn greedy_coloring<G>(g: G) -> (Vec<usize>, usize)
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let ix = |n| g.to_index(n);
+    let other = |n, edge: G::EdgeRef| {
+        if edge.source() == n {
+            edge.target()
+        } else {
+            edge.source()
+        }
+    };
+
+    let mut degree = vec![0; g.node_bound()];
+    for n in g.node_identifiers() {
+        degree[ix(n)] = undirected_edges(g, n)
+            .filter(|edge| edge.source() != edge.target())
+            .count();
+    }
+    let mut order: Vec<_> = g.node_identifiers().collect();
+    order.sort_by_key(|&n| std::cmp::Reverse(degree[ix(n)]));
+
+    let mut color: Vec<Option<usize>> = vec![None; g.node_bound()];
+    // `used[c] == i` if the color `c` is used by a neighbor of the `i`-th node of `order`
+    let mut used = Vec::new();
+    let mut count = 0;
+    for (i, &n) in order.iter().enumerate() {
+        for edge in undirected_edges(g, n) {
+            if let Some(c) = color[ix(other(n, edge))] {
+                used[c] = i;
+            }
+        }
+        let c = (0..count).find(|&c| used[c] != i).unwrap_or(count);
+        if c == count {
+            count += 1;
+            used.push(std::usize::MAX);
+        }
+        color[ix(n)] = Some(c);
+    }
+    (color.into_iter().map(|c| c.unwrap_or(0)).collect(), count)
+}
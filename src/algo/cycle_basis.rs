@@ -0,0 +1,111 @@
+//! Cycle bases.
+
+use crate::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoEdgesDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+use super::undirected_edges;
+
+/// \[Generic\] Compute a *fundamental cycle basis* of a graph.
+///
+/// The input graph is treated as if undirected.
+///
+/// A depth-first search spans each connected component with a tree, and each edge that is not
+/// in the trees closes a cycle with the path between its endpoints in the tree. Every cycle of
+/// the graph is a combination of these fundamental cycles, and there are **|E| - |V| + c** of
+/// them, where **c** is the number of connected components in `g`. A self loop is a cycle of
+/// one node, and an edge parallel to a tree edge is a cycle of two nodes.
+///
+/// Runtime is **O(|V| + |E| + L)**, where **L** is the total length of the cycles.
+///
+/// Return the cycles, each as the list of its nodes in order along the cycle: the edge that
+/// closes it joins the last node to the first.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::cycle_basis;
+/// use petgraph::graph::UnGraph;
+///
+/// // a square and a triangle, joined at the node 0
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 4), (4, 5), (5, 0)]);
+/// let mut lengths: Vec<_> = cycle_basis(&g).iter().map(|cycle| cycle.len()).collect();
+/// lengths.sort();
+/// assert_eq!(lengths, vec![3, 4]);
+/// ```
+pub fn cycle_basis<G>(g: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgesDirected
+        + IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeIndexable
+        + GraphProp,
+{
+    let ix = |n| NodeIndexable::to_index(&g, n);
+
+    // The parent of each node in the forest, with the edge to it, and the depth of the node;
+    // the depth of a node that is not visited yet is `None`
+    let mut parent = vec![None; g.node_bound()];
+    let mut depth = vec![None; g.node_bound()];
+    let mut tree_edge = vec![false; g.edge_bound()];
+    let mut stack = Vec::new();
+    for root in g.node_identifiers() {
+        if depth[ix(root)].is_some() {
+            continue;
+        }
+        depth[ix(root)] = Some(0);
+        stack.push((root, undirected_edges(g, root)));
+        while let Some((node, edges)) = stack.last_mut() {
+            let node = *node;
+            let next = edges.find_map(|edge| {
+                let next = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if depth[ix(next)].is_none() {
+                    Some((edge.id(), next))
+                } else {
+                    None
+                }
+            });
+            match next {
+                Some((edge, next)) => {
+                    tree_edge[EdgeIndexable::to_index(&g, edge)] = true;
+                    parent[ix(next)] = Some(node);
+                    depth[ix(next)] = depth[ix(node)].map(|d: usize| d + 1);
+                    stack.push((next, undirected_edges(g, next)));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    let mut cycles = Vec::new();
+    for edge in g.edge_references() {
+        if tree_edge[EdgeIndexable::to_index(&g, edge.id())] {
+            continue;
+        }
+        // Climb from both endpoints to their lowest common ancestor: the path from the
+        // source goes up, and the one from the target is reversed to come down
+        let (mut a, mut b) = (edge.source(), edge.target());
+        let mut up = Vec::new();
+        let mut down = Vec::new();
+        while a != b {
+            if depth[ix(a)] >= depth[ix(b)] {
+                up.push(a);
+                a = parent[ix(a)].unwrap();
+            } else {
+                down.push(b);
+                b = parent[ix(b)].unwrap();
+            }
+        }
+        up.push(a);
+        up.extend(down.into_iter().rev());
+        cycles.push(up);
+    }
+    cycles
+}
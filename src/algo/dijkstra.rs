@@ -17,9 +17,11 @@ use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
 /// to compute path costs. Edge costs must be non-negative.
 ///
 /// If `goal` is not `None`, then the algorithm terminates once the `goal` node's
-/// cost is calculated.
+/// cost is calculated. The costs of the other nodes that were reached, but not yet
+/// visited, are then only upper bounds.
 ///
-/// Returns a `HashMap` that maps `NodeId` to path cost.
+/// Returns a `HashMap` that maps `NodeId` to path cost. Nodes that are not
+/// reachable from `start` are not in the map.
 /// # Example
 /// ```rust
 /// use petgraph::Graph;
@@ -0,0 +1,127 @@
+//! Eulerian paths.
+
+use crate::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoEdgesDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use crate::Direction::Outgoing;
+
+/// \[Generic\] Find an *Eulerian path*: a path that uses every edge of the graph exactly once.
+///
+/// Such a path exists if the edges are connected, and:
+///
+/// - in an undirected graph, zero or two nodes have an odd degree; the path starts at one of
+///   them, and ends at the other.
+/// - in a directed graph, every node has as many incoming as outgoing edges, except possibly
+///   for a start node with one more outgoing edge, and an end node with one more incoming edge.
+///
+/// Otherwise, return `None`. If every degree is even, or balanced, the path is a circuit: it
+/// ends where it starts. Isolated nodes are ignored. A graph without edges has an empty path.
+///
+/// Using Hierholzer's algorithm, with runtime **O(|V| + |E|)**: a trail is followed from the
+/// start until it gets stuck, and the unused edges of its nodes are spliced in as cycles.
+///
+/// Return the edges of the path, in order.
+///
+/// This implementation is iterative.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::eulerian_path;
+/// use petgraph::graph::{edge_index, UnGraph};
+///
+/// // a triangle with a tail: 0 - 1 - 2 - 3 - 1
+/// let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+/// let path = eulerian_path(&g).unwrap();
+/// assert_eq!(path.len(), 4);
+/// assert_eq!(path[0], edge_index(0));
+///
+/// // with another tail, four nodes have an odd degree
+/// g.extend_with_edges(&[(2, 4)]);
+/// assert_eq!(eulerian_path(&g), None);
+/// ```
+pub fn eulerian_path<G>(g: G) -> Option<Vec<G::EdgeId>>
+where
+    G: IntoEdgesDirected
+        + IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeIndexable
+        + GraphProp,
+{
+    let ix = |n| NodeIndexable::to_index(&g, n);
+
+    // For an undirected graph, the degree of each node,
+    // and for a directed graph, its outgoing minus its incoming edges.
+    let mut degree = vec![0isize; g.node_bound()];
+    let mut edge_count = 0;
+    for edge in g.edge_references() {
+        edge_count += 1;
+        degree[ix(edge.source())] += 1;
+        degree[ix(edge.target())] += if g.is_directed() { -1 } else { 1 };
+    }
+    if edge_count == 0 {
+        return Some(Vec::new());
+    }
+
+    let mut start = None;
+    let mut unbalanced = 0;
+    for n in g.node_identifiers() {
+        let d = degree[ix(n)];
+        let is_start = if g.is_directed() { d == 1 } else { d % 2 != 0 };
+        let is_end = g.is_directed() && d == -1;
+        if is_start || is_end {
+            unbalanced += 1;
+        } else if g.is_directed() && d != 0 {
+            return None;
+        }
+        if is_start && start.is_none() {
+            start = Some(n);
+        }
+    }
+    if unbalanced > 2 || unbalanced == 1 {
+        return None;
+    }
+    // Any node with edges starts a circuit
+    let start = match start {
+        Some(n) => n,
+        None => g
+            .node_identifiers()
+            .find(|&n| g.edges_directed(n, Outgoing).next().is_some())?,
+    };
+
+    let mut used = vec![false; g.edge_bound()];
+    let mut edges: Vec<Option<G::EdgesDirected>> = (0..g.node_bound()).map(|_| None).collect();
+    // The current trail, as each node with the edge used to get to it
+    let mut trail = vec![(start, None)];
+    let mut path = Vec::with_capacity(edge_count);
+    while let Some(&(u, _)) = trail.last() {
+        let unused = edges[ix(u)]
+            .get_or_insert_with(|| g.edges_directed(u, Outgoing))
+            .find(|edge| !used[EdgeIndexable::to_index(&g, edge.id())]);
+        match unused {
+            Some(edge) => {
+                used[EdgeIndexable::to_index(&g, edge.id())] = true;
+                let v = if edge.source() == u {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                trail.push((v, Some(edge.id())));
+            }
+            None => {
+                // Stuck: the trail ends here, and is completed backwards
+                if let Some((_, Some(e))) = trail.pop() {
+                    path.push(e);
+                }
+            }
+        }
+    }
+
+    // The edges are not connected
+    if path.len() != edge_count {
+        return None;
+    }
+    path.reverse();
+    Some(path)
+}
@@ -17,7 +17,9 @@ use crate::visit::{
 /// * `edge_cost`: closure that returns cost of a particular edge
 ///
 /// # Returns
-/// * `Ok`: (if graph contains no negative cycle) a hashmap containing all pairs shortest paths
+/// * `Ok`: (if graph contains no negative cycle) a hashmap containing all pairs shortest paths.
+///   Every pair of nodes has an entry; if there is no path from the first node to the second,
+///   the distance is `K::max()` (see [`BoundedMeasure`]).
 /// * `Err`: if graph contains negative cycle.
 ///
 /// # Examples
@@ -93,22 +95,31 @@ where
     // |V|x|V| matrix
     let mut dist = vec![vec![K::max(); num_of_nodes]; num_of_nodes];
 
-    // init distances of paths with no intermediate nodes
-    for edge in graph.edge_references() {
-        dist[graph.to_index(edge.source())][graph.to_index(edge.target())] = edge_cost(edge);
-        if !graph.is_directed() {
-            dist[graph.to_index(edge.target())][graph.to_index(edge.source())] = edge_cost(edge);
-        }
-    }
-
     // distance of each node to itself is 0(default value)
     for node in graph.node_identifiers() {
         dist[graph.to_index(node)][graph.to_index(node)] = K::default();
     }
 
+    // init distances of paths with no intermediate nodes, keeping the cheapest of parallel
+    // edges, and a negative self loop as a negative cycle
+    for edge in graph.edge_references() {
+        let (i, j) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        let cost = edge_cost(edge);
+        if cost < dist[i][j] {
+            dist[i][j] = cost;
+            if !graph.is_directed() {
+                dist[j][i] = cost;
+            }
+        }
+    }
+
     for k in 0..num_of_nodes {
         for i in 0..num_of_nodes {
             for j in 0..num_of_nodes {
+                // an unreachable pair must stay unreachable, even across negative edges
+                if dist[i][k] == K::max() || dist[k][j] == K::max() {
+                    continue;
+                }
                 let (result, overflow) = dist[i][k].overflowing_add(dist[k][j]);
                 if !overflow && dist[i][j] > result {
                     dist[i][j] = result;
@@ -49,18 +49,21 @@ where
 }
 
 /// Tells whether there is an augmented path in the graph
+///
+/// If there is none, `visited` holds the nodes reachable from `source` in the residual network.
 fn has_augmented_path<N>(
     network: N,
     source: N::NodeId,
     destination: N::NodeId,
     edge_to: &mut [Option<N::EdgeRef>],
     flows: &[N::EdgeWeight],
+    visited: &mut N::Map,
 ) -> bool
 where
     N: NodeCount + IntoEdgesDirected + NodeIndexable + EdgeIndexable + Visitable,
     N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
 {
-    let mut visited = network.visit_map();
+    network.reset_map(visited);
     let mut queue = VecDeque::new();
     visited.visit(source);
     queue.push_back(source);
@@ -114,6 +117,13 @@ where
 ///
 /// If it terminates, it returns the maximum flow and also the computed edge flows.
 ///
+/// The edge weights are the capacities, and the flow of each edge is at index
+/// `network.to_index(edge)` of the returned vector. Each augmenting path is a
+/// shortest one, found by breadth-first search in the residual network, as in the
+/// Edmonds–Karp algorithm; the runtime is **O(|V| |E|²)**. Edges in opposite
+/// directions between two nodes carry their own flows, and edges of zero
+/// capacity carry no flow.
+///
 /// [ff]: https://en.wikipedia.org/wiki/Ford%E2%80%93Fulkerson_algorithm
 ///
 /// # Example
@@ -158,10 +168,18 @@ where
         + Visitable,
     N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
 {
-    let mut edge_to = vec![None; network.node_count()];
-    let mut flows = vec![N::EdgeWeight::zero(); network.edge_count()];
+    let mut edge_to = vec![None; network.node_bound()];
+    let mut flows = vec![N::EdgeWeight::zero(); network.edge_bound()];
     let mut max_flow = N::EdgeWeight::zero();
-    while has_augmented_path(&network, source, destination, &mut edge_to, &flows) {
+    let mut visited = network.visit_map();
+    while has_augmented_path(
+        &network,
+        source,
+        destination,
+        &mut edge_to,
+        &flows,
+        &mut visited,
+    ) {
         let mut path_flow = N::EdgeWeight::max();
 
         // Find the bottleneck capacity of the path
@@ -194,3 +212,73 @@ where
     }
     (max_flow, flows)
 }
+
+/// \[Generic\] Minimum cut between `source` and `destination`.
+///
+/// Computes the [minimum cut][mc] of a weighted directed graph: a set of edges of least total
+/// weight whose removal leaves no path from `source` to `destination`. By the max-flow min-cut
+/// theorem, its weight is the maximum flow.
+///
+/// The maximum flow is computed with [`ford_fulkerson`], and the cut consists of the edges
+/// from the nodes that are reachable from `source` in the residual network to the other nodes.
+///
+/// Returns the weight of the cut and its edges.
+///
+/// [mc]: https://en.wikipedia.org/wiki/Max-flow_min-cut_theorem
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::min_cut;
+/// use petgraph::graph::edge_index;
+///
+/// // Two paths from 0 to 3, with the bottlenecks 1 -> 3 and 0 -> 2
+/// let graph = Graph::<(), u32>::from_edges(&[(0, 1, 5), (1, 3, 2), (0, 2, 1), (2, 3, 7)]);
+/// let (weight, mut cut) = min_cut(&graph, 0.into(), 3.into());
+/// cut.sort();
+/// assert_eq!(weight, 3);
+/// assert_eq!(cut, vec![edge_index(1), edge_index(2)]);
+/// ```
+pub fn min_cut<N>(
+    network: N,
+    source: N::NodeId,
+    destination: N::NodeId,
+) -> (N::EdgeWeight, Vec<N::EdgeId>)
+where
+    N: NodeCount
+        + EdgeCount
+        + IntoEdgesDirected
+        + EdgeIndexable
+        + NodeIndexable
+        + DataMap
+        + Visitable,
+    N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
+{
+    let (max_flow, flows) = ford_fulkerson(&network, source, destination);
+
+    let mut edge_to = vec![None; network.node_bound()];
+    let mut reachable = network.visit_map();
+    let found = has_augmented_path(
+        &network,
+        source,
+        destination,
+        &mut edge_to,
+        &flows,
+        &mut reachable,
+    );
+    debug_assert!(!found);
+
+    let mut cut = Vec::new();
+    for i in 0..network.node_bound() {
+        let node = NodeIndexable::from_index(&network, i);
+        if !reachable.is_visited(&node) {
+            continue;
+        }
+        for edge in network.edges_directed(node, Direction::Outgoing) {
+            if !reachable.is_visited(&edge.target()) {
+                cut.push(edge.id());
+            }
+        }
+    }
+    (max_flow, cut)
+}
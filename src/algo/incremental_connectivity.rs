@@ -0,0 +1,108 @@
+//! Connectivity of a graph that grows.
+
+use std::fmt;
+
+use crate::graph::{DefaultIx, Graph, IndexType, NodeIndex};
+use crate::unionfind::UnionFind;
+use crate::EdgeType;
+
+/// Answer whether two nodes are connected, while edges are added to a graph.
+///
+/// Each added edge unifies the components of its endpoints, in a `UnionFind` of the node
+/// indices, so queries and additions take amortized **O(α(|V|))** time instead of recomputing
+/// the components. Edge directions are ignored: the components are the weakly connected ones.
+///
+/// **Note:** only additions are supported. A union-find structure can not split a set, so
+/// after an edge is removed from the graph, a new `IncrementalConnectivity` has to be built.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::IncrementalConnectivity;
+/// use petgraph::graph::UnGraph;
+///
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 3)]);
+/// let mut connectivity = IncrementalConnectivity::from_graph(&graph);
+/// assert_eq!(connectivity.component_count(), 2);
+///
+/// let (a, d) = (0.into(), 3.into());
+/// assert!(!connectivity.connected(a, d));
+/// graph.add_edge(1.into(), 2.into(), ());
+/// connectivity.add_edge(1.into(), 2.into());
+/// assert!(connectivity.connected(a, d));
+/// assert_eq!(connectivity.component_count(), 1);
+/// ```
+#[derive(Clone)]
+pub struct IncrementalConnectivity<Ix = DefaultIx> {
+    components: UnionFind<Ix>,
+    component_count: usize,
+}
+
+impl<Ix> fmt::Debug for IncrementalConnectivity<Ix>
+where
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IncrementalConnectivity")
+            .field("components", &self.components)
+            .field("component_count", &self.component_count)
+            .finish()
+    }
+}
+
+impl<Ix> IncrementalConnectivity<Ix>
+where
+    Ix: IndexType,
+{
+    /// Create a new `IncrementalConnectivity` of `n` nodes without edges.
+    pub fn new(n: usize) -> Self {
+        IncrementalConnectivity {
+            components: UnionFind::new(n),
+            component_count: n,
+        }
+    }
+
+    /// Create a new `IncrementalConnectivity` of the nodes and edges of `g`.
+    pub fn from_graph<N, E, Ty>(g: &Graph<N, E, Ty, Ix>) -> Self
+    where
+        Ty: EdgeType,
+    {
+        let mut connectivity = Self::new(g.node_count());
+        for edge in g.raw_edges() {
+            connectivity.add_edge(edge.source(), edge.target());
+        }
+        connectivity
+    }
+
+    /// Add a node without edges, and return its index.
+    ///
+    /// The indices are the same as those of `Graph::add_node`, when nodes are added to both.
+    pub fn add_node(&mut self) -> NodeIndex<Ix> {
+        self.component_count += 1;
+        NodeIndex::new(self.components.push().index())
+    }
+
+    /// Add an edge between `a` and `b`, joining their components.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) {
+        if self
+            .components
+            .union(Ix::new(a.index()), Ix::new(b.index()))
+        {
+            self.component_count -= 1;
+        }
+    }
+
+    /// Return `true` if there is a path between `a` and `b`.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    pub fn connected(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.components
+            .equiv_mut(Ix::new(a.index()), Ix::new(b.index()))
+    }
+
+    /// Return the number of connected components.
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+}
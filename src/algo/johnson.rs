@@ -0,0 +1,118 @@
+//! Johnson's algorithm.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::algo::{dijkstra, BoundedMeasure, NegativeCycle};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeIndexable,
+    Visitable,
+};
+
+/// \[Generic\] [Johnson's algorithm](https://en.wikipedia.org/wiki/Johnson%27s_algorithm)
+/// for the all pairs shortest path problem.
+///
+/// Compute shortest paths in a weighted graph with positive or negative edge weights (but with
+/// no negative cycles). For sparse graphs, this is faster than [`floyd_warshall`][fw].
+///
+/// The Bellman-Ford algorithm, from a virtual node with an edge of cost zero to every node,
+/// computes a potential `h` for each node. The edge costs are reweighted to
+/// `cost + h(source) - h(target)`, which is never negative, and the shortest paths from each
+/// node are computed with [`dijkstra`] on the reweighted graph.
+///
+/// The runtime is **O(|V||E| log |V|)**, and the function `edge_cost` is called
+/// **O(|V||E|)** times.
+///
+/// # Arguments
+/// * `graph`: graph with no negative cycle
+/// * `edge_cost`: closure that returns cost of a particular edge
+///
+/// # Returns
+/// * `Ok`: (if graph contains no negative cycle) a hashmap containing all pairs shortest paths.
+///   As with [`floyd_warshall`][fw], every pair of nodes has an entry; if there is no path from
+///   the first node to the second, the distance is `K::max()` (see [`BoundedMeasure`]).
+/// * `Err`: if graph contains negative cycle.
+///
+/// [fw]: crate::algo::floyd_warshall
+///
+/// # Examples
+/// ```rust
+/// use petgraph::algo::johnson;
+/// use petgraph::graph::{node_index, Graph};
+///
+/// //  0 --3--> 1 --(-2)--> 2
+/// //  |                    ^
+/// //   ---------2----------
+/// let graph = Graph::<(), i32>::from_edges(&[(0, 1, 3), (1, 2, -2), (0, 2, 2)]);
+/// let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+///
+/// assert_eq!(res[&(node_index(0), node_index(2))], 1);
+/// assert_eq!(res[&(node_index(1), node_index(2))], -2);
+/// assert_eq!(res[&(node_index(2), node_index(0))], std::i32::MAX);
+///
+/// // a negative cycle
+/// let graph = Graph::<(), i32>::from_edges(&[(0, 1, 1), (1, 0, -2)]);
+/// assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn johnson<G, F, K>(
+    graph: G,
+    mut edge_cost: F,
+) -> Result<HashMap<(G::NodeId, G::NodeId), K>, NegativeCycle>
+where
+    G: IntoEdges + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy,
+{
+    let ix = |n| graph.to_index(n);
+
+    // Bellman-Ford from the virtual node: the potentials start as the distances through the
+    // virtual edges alone, and |V| - 1 rounds relax all the paths from it
+    let mut potential = vec![K::default(); graph.node_bound()];
+    let node_count = graph.node_identifiers().count();
+    let mut relax = |potential: &mut [K], edge: G::EdgeRef| {
+        let cost = edge_cost(edge);
+        let mut lower = |u, v| {
+            let through = potential[ix(u)] + cost;
+            if through < potential[ix(v)] {
+                potential[ix(v)] = through;
+                true
+            } else {
+                false
+            }
+        };
+        let forward = lower(edge.source(), edge.target());
+        let backward = !graph.is_directed() && lower(edge.target(), edge.source());
+        forward || backward
+    };
+    for _ in 1..node_count {
+        let mut changed = false;
+        for edge in graph.edge_references() {
+            changed |= relax(&mut potential, edge);
+        }
+        if !changed {
+            break;
+        }
+    }
+    for edge in graph.edge_references() {
+        if relax(&mut potential, edge) {
+            return Err(NegativeCycle(()));
+        }
+    }
+
+    let mut distance_map = HashMap::with_capacity(node_count * node_count);
+    for source in graph.node_identifiers() {
+        let reweighted = dijkstra(graph, source, None, |edge| {
+            edge_cost(edge) + potential[ix(edge.source())] - potential[ix(edge.target())]
+        });
+        for target in graph.node_identifiers() {
+            let distance = match reweighted.get(&target) {
+                Some(&d) => d - potential[ix(source)] + potential[ix(target)],
+                None => K::max(),
+            };
+            distance_map.insert((source, target), distance);
+        }
+    }
+    Ok(distance_map)
+}
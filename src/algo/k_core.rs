@@ -0,0 +1,100 @@
+//! k-core decomposition.
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+
+use super::undirected_edges;
+
+/// \[Generic\] Compute the *coreness* of every node: the largest `k` such that the node
+/// belongs to a *k-core*, a maximal subgraph in which every node has degree at least `k`.
+///
+/// The input graph is treated as if undirected: the degree of a node in a directed graph
+/// is the number of its incoming and outgoing edges together.
+///
+/// Self loops are ignored. Parallel edges are all counted, so two nodes joined by two
+/// edges contribute two to each other's degree.
+///
+/// Using the peeling algorithm of Batagelj and Zaversnik, with runtime **O(|V| + |E|)**:
+/// nodes are kept in a bucket queue ordered by their remaining degree, and the node of
+/// minimum degree is removed repeatedly, lowering the degree of its neighbors.
+///
+/// Return a vector indexed by `g.to_index(node)`, of length `g.node_bound()`.
+/// Indices that belong to no node have coreness 0.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::k_core;
+/// use petgraph::graph::UnGraph;
+///
+/// // a triangle with a tail: 0 - 1 - 2 - 3 - 1
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+/// assert_eq!(k_core(&g), vec![1, 2, 2, 2]);
+/// ```
+pub fn k_core<G>(g: G) -> Vec<usize>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let ix = |n| g.to_index(n);
+    let other = |n, edge: G::EdgeRef| {
+        if edge.source() == n {
+            edge.target()
+        } else {
+            edge.source()
+        }
+    };
+
+    let mut degree = vec![0; g.node_bound()];
+    let mut max_degree = 0;
+    for n in g.node_identifiers() {
+        let d = undirected_edges(g, n)
+            .filter(|edge| edge.source() != edge.target())
+            .count();
+        degree[ix(n)] = d;
+        max_degree = max_degree.max(d);
+    }
+
+    // Sort the nodes by degree: `order` is the bucket queue, where the nodes of degree `d`
+    // start at `bucket_start[d]`, and `position` is the inverse of `order`
+    let mut bucket_start = vec![0; max_degree + 1];
+    for n in g.node_identifiers() {
+        bucket_start[degree[ix(n)]] += 1;
+    }
+    let mut start = 0;
+    for count in &mut bucket_start {
+        let next = start + *count;
+        *count = start;
+        start = next;
+    }
+    let nodes: Vec<_> = g.node_identifiers().collect();
+    let mut order = nodes.clone();
+    let mut position = vec![0; g.node_bound()];
+    let mut next = bucket_start.clone();
+    for n in nodes {
+        let d = degree[ix(n)];
+        position[ix(n)] = next[d];
+        order[next[d]] = n;
+        next[d] += 1;
+    }
+
+    // Peel the nodes in increasing order of degree; the degree of a node when it is
+    // removed is its coreness
+    for i in 0..order.len() {
+        let v = order[i];
+        for edge in undirected_edges(g, v) {
+            let u = other(v, edge);
+            if degree[ix(u)] > degree[ix(v)] {
+                // Move `u` to the front of its bucket, then shrink the bucket past it
+                let du = degree[ix(u)];
+                let front = bucket_start[du];
+                let w = order[front];
+                if u != w {
+                    order.swap(position[ix(u)], front);
+                    position[ix(w)] = position[ix(u)];
+                    position[ix(u)] = front;
+                }
+                bucket_start[du] += 1;
+                degree[ix(u)] -= 1;
+            }
+        }
+    }
+    degree
+}
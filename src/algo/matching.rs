@@ -2,10 +2,12 @@ use std::collections::VecDeque;
 use std::hash::Hash;
 
 use crate::visit::{
-    EdgeRef, GraphBase, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable,
-    VisitMap, Visitable,
+    EdgeRef, GraphBase, GraphProp, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+    IntoNodeIdentifiers, NodeCount, NodeIndexable, VisitMap, Visitable,
 };
 
+use super::{bipartite_coloring, undirected_edges};
+
 /// Computed
 /// [*matching*](https://en.wikipedia.org/wiki/Matching_(graph_theory)#Definitions)
 /// of the graph.
@@ -490,6 +492,137 @@ where
     Matching::new(graph, mate, n_edges)
 }
 
+/// \[Generic\] Compute the [*maximum
+/// matching*](https://en.wikipedia.org/wiki/Matching_(graph_theory)) of a
+/// bipartite graph using the [Hopcroft–Karp algorithm][1].
+///
+/// [1]: https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm
+///
+/// The input graph is treated as if undirected. Its two sides are found with
+/// [`bipartite_coloring`](crate::algo::bipartite_coloring), so they may have
+/// different sizes, and each connected component may be arbitrarily split.
+/// The algorithm runs in *O(|E| √|V|)*.
+///
+/// Return `None` if the graph is not bipartite. [`maximum_matching`] works on
+/// any graph, but is slower.
+///
+/// This implementation is iterative.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::algo::maximum_bipartite_matching;
+///
+/// // Three workers, and four jobs that the workers can do:
+/// // worker 0 can only do job 4, and worker 1 can only do job 4 or 5.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 4), (1, 4), (1, 5), (2, 3), (2, 4), (2, 6)]);
+///
+/// let matching = maximum_bipartite_matching(&graph).unwrap();
+/// assert_eq!(matching.len(), 3);
+/// assert_eq!(matching.mate(NodeIndex::new(0)), Some(NodeIndex::new(4)));
+/// assert_eq!(matching.mate(NodeIndex::new(1)), Some(NodeIndex::new(5)));
+///
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert!(maximum_bipartite_matching(&triangle).is_none());
+/// ```
+pub fn maximum_bipartite_matching<G>(graph: G) -> Option<Matching<G>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    const UNREACHED: usize = std::usize::MAX;
+
+    let side = bipartite_coloring(graph)?;
+    let ix = |n| graph.to_index(n);
+    let other = |n, edge: G::EdgeRef| {
+        if edge.source() == n {
+            edge.target()
+        } else {
+            edge.source()
+        }
+    };
+    let left: Vec<G::NodeId> = graph.node_identifiers().filter(|&n| !side[ix(n)]).collect();
+
+    let mut mate: Vec<Option<G::NodeId>> = vec![None; graph.node_bound()];
+    let mut n_edges = 0;
+    // The layer of each left node in the search for shortest augmenting paths
+    let mut layer = vec![UNREACHED; graph.node_bound()];
+    let mut queue = VecDeque::new();
+    let mut stack = Vec::new();
+    let mut path = Vec::new();
+
+    loop {
+        // Layer the left nodes by breadth-first search from the free ones,
+        // alternating between unmatched and matched edges
+        for &u in &left {
+            if mate[ix(u)].is_none() {
+                layer[ix(u)] = 0;
+                queue.push_back(u);
+            } else {
+                layer[ix(u)] = UNREACHED;
+            }
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for edge in undirected_edges(graph, u) {
+                match mate[ix(other(u, edge))] {
+                    None => found = true,
+                    Some(w) => {
+                        if layer[ix(w)] == UNREACHED {
+                            layer[ix(w)] = layer[ix(u)] + 1;
+                            queue.push_back(w);
+                        }
+                    }
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+
+        // Find a maximal set of disjoint shortest augmenting paths by depth-first search
+        // through the layers; `path` holds the right node chosen at each level of `stack`
+        for &start in &left {
+            if mate[ix(start)].is_some() {
+                continue;
+            }
+            stack.push((start, undirected_edges(graph, start)));
+            while let Some(&mut (u, ref mut edges)) = stack.last_mut() {
+                let v = match edges.next() {
+                    Some(edge) => other(u, edge),
+                    None => {
+                        // No augmenting path goes through `u`
+                        layer[ix(u)] = UNREACHED;
+                        stack.pop();
+                        path.pop();
+                        continue;
+                    }
+                };
+                match mate[ix(v)] {
+                    None => {
+                        // Augment along the path, and stop this search
+                        path.push(v);
+                        for ((u, _), &v) in stack.drain(..).zip(&path) {
+                            mate[ix(u)] = Some(v);
+                            mate[ix(v)] = Some(u);
+                        }
+                        path.clear();
+                        n_edges += 1;
+                    }
+                    Some(w) => {
+                        if layer[ix(w)] != UNREACHED && layer[ix(w)] == layer[ix(u)] + 1 {
+                            path.push(v);
+                            stack.push((w, undirected_edges(graph, w)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Some(Matching::new(graph, mate, n_edges))
+}
+
 fn find_join<G, F>(
     graph: &G,
     edge: G::EdgeRef,
@@ -1,15 +1,20 @@
 //! Minimum Spanning Tree algorithms.
 
 use std::collections::{BinaryHeap, HashMap};
+use std::ops::Sub;
 
 use crate::prelude::*;
 
 use crate::data::Element;
+use crate::graph::IndexType;
 use crate::scored::MinScored;
 use crate::unionfind::UnionFind;
-use crate::visit::{Data, IntoNodeReferences, NodeRef};
+use crate::visit::{Data, IntoNodeIdentifiers, IntoNodeReferences, NodeRef};
+use crate::visit::{GraphProp, IntoEdgesDirected, VisitMap, Visitable};
 use crate::visit::{IntoEdgeReferences, NodeIndexable};
 
+use super::undirected_edges;
+
 /// \[Generic\] Compute a *minimum spanning tree* of a graph.
 ///
 /// The input graph is treated as if undirected.
@@ -115,3 +120,309 @@ where
         None
     }
 }
+
+/// \[Generic\] Compute a *minimum spanning tree* of the connected component of `start`.
+///
+/// The input graph is treated as if undirected.
+///
+/// Using Prim's algorithm with runtime **O(|E| log |E|)**: the tree is grown from `start`,
+/// always adding the lightest edge that connects it to a node not in the tree yet. Unlike
+/// `min_spanning_tree`, this does not need a union-find, but it only spans the nodes that
+/// are connected to `start`. Use `min_spanning_tree` for a minimum spanning forest of the
+/// whole graph.
+///
+/// Return the ids of the edges of the tree, in the order they were added. The tree has
+/// **k - 1** edges, where **k** is the number of nodes connected to `start`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::prim_mst;
+/// use petgraph::graph::{edge_index, node_index, UnGraph};
+///
+/// let mut g = UnGraph::<(), u32>::new_undirected();
+/// g.extend_with_edges(&[(0, 1, 3), (1, 2, 1), (0, 2, 2), (3, 4, 1)]);
+///
+/// let tree = prim_mst(&g, node_index(0));
+/// assert_eq!(tree, vec![edge_index(2), edge_index(1)]);
+/// ```
+pub fn prim_mst<G>(g: G, start: G::NodeId) -> Vec<G::EdgeId>
+where
+    G: IntoEdgesDirected + Visitable + GraphProp,
+    G::EdgeWeight: Clone + PartialOrd,
+{
+    let mut in_tree = g.visit_map();
+    let mut crossing = BinaryHeap::new();
+    let mut tree = Vec::new();
+
+    // Push the edges from `node` to the nodes that are not in the tree yet
+    let push_crossing = |node: G::NodeId, in_tree: &G::Map, crossing: &mut BinaryHeap<_>| {
+        for edge in undirected_edges(g, node) {
+            let next = if edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            if !in_tree.is_visited(&next) {
+                crossing.push(MinScored(edge.weight().clone(), (edge.id(), next)));
+            }
+        }
+    };
+
+    in_tree.visit(start);
+    push_crossing(start, &in_tree, &mut crossing);
+    while let Some(MinScored(_, (edge, next))) = crossing.pop() {
+        // `next` may have been added through a lighter edge since
+        if !in_tree.visit(next) {
+            continue;
+        }
+        tree.push(edge);
+        push_crossing(next, &in_tree, &mut crossing);
+    }
+    tree
+}
+
+/// Compute a *minimum spanning arborescence* of a directed graph: a spanning tree of
+/// minimum total weight, with every edge directed away from `root`.
+///
+/// Every node other than `root` has exactly one incoming edge in the arborescence. Return
+/// `None` if some node is not reachable from `root`, so that no arborescence exists.
+///
+/// Using the Chu–Liu/Edmonds algorithm, with runtime **O(|V| |E|)**: the cheapest incoming
+/// edge of each node is selected, and while these contain a cycle, the cycle is contracted
+/// into a single node, with the weight of each edge entering it reduced by the weight of the
+/// selected edge it would replace. The weights are only subtracted from weights at least as
+/// large, so unsigned integers do not underflow.
+///
+/// Return the indices of the edges of the arborescence, ordered by the index of their target.
+/// Self loops and edges into `root` are never used.
+///
+/// **Panics** if `root` is not a node of `g`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::min_spanning_arborescence;
+/// use petgraph::graph::{edge_index, node_index, DiGraph};
+///
+/// let g = DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 10),
+///     (0, 2, 10),
+///     (1, 2, 1),
+///     (2, 1, 1),
+/// ]);
+/// // 1 and 2 prefer each other, so one of them is entered from the root
+/// let tree = min_spanning_arborescence(&g, node_index(0)).unwrap();
+/// assert_eq!(tree, vec![edge_index(0), edge_index(2)]);
+///
+/// // nothing reaches the root
+/// assert_eq!(min_spanning_arborescence(&g, node_index(1)), None);
+/// ```
+pub fn min_spanning_arborescence<N, E, Ix>(
+    g: &Graph<N, E, Directed, Ix>,
+    root: NodeIndex<Ix>,
+) -> Option<Vec<EdgeIndex<Ix>>>
+where
+    E: Ord + Clone + Sub<E, Output = E>,
+    Ix: IndexType,
+{
+    assert!(root.index() < g.node_count(), "root is not in the graph");
+    const NONE: usize = std::usize::MAX;
+
+    // Each contraction is a level, with its edges numbered in the level before it
+    struct Level<E> {
+        root: usize,
+        // (source, target, weight) of the edges
+        edges: Vec<(usize, usize, E)>,
+        // the number of each edge in the level before, or its index in `g`
+        parent: Vec<usize>,
+        // the cheapest incoming edge of each node, and the cycle it is in, if any
+        cheapest: Vec<usize>,
+        cycle: Vec<usize>,
+        cycle_count: usize,
+    }
+
+    let mut edges = Vec::with_capacity(g.edge_count());
+    let mut parent = Vec::with_capacity(g.edge_count());
+    for (i, edge) in g.raw_edges().iter().enumerate() {
+        let (u, v) = (edge.source().index(), edge.target().index());
+        if u != v && v != root.index() {
+            edges.push((u, v, edge.weight.clone()));
+            parent.push(i);
+        }
+    }
+    let mut levels = Vec::new();
+    let mut n = g.node_count();
+    let mut root = root.index();
+    loop {
+        let mut cheapest = vec![NONE; n];
+        for (i, (_, v, w)) in edges.iter().enumerate() {
+            if cheapest[*v] == NONE || *w < edges[cheapest[*v]].2 {
+                cheapest[*v] = i;
+            }
+        }
+        if (0..n).any(|v| v != root && cheapest[v] == NONE) {
+            return None;
+        }
+
+        // Follow the cheapest edges backwards from each node, until the root, a node
+        // of an earlier walk, or a node of this walk, which closes a new cycle
+        let mut cycle = vec![NONE; n];
+        let mut walk = vec![NONE; n];
+        let mut cycle_count = 0;
+        for start in 0..n {
+            let mut v = start;
+            while v != root && walk[v] == NONE {
+                walk[v] = start;
+                v = edges[cheapest[v]].0;
+            }
+            if v != root && walk[v] == start {
+                while cycle[v] == NONE {
+                    cycle[v] = cycle_count;
+                    v = edges[cheapest[v]].0;
+                }
+                cycle_count += 1;
+            }
+        }
+
+        if cycle_count == 0 {
+            levels.push(Level {
+                root,
+                edges,
+                parent,
+                cheapest,
+                cycle,
+                cycle_count,
+            });
+            break;
+        }
+
+        // Number the contracted nodes, with each cycle as one node
+        let mut node = vec![NONE; n];
+        let mut cycle_node = vec![NONE; cycle_count];
+        let mut count = 0;
+        for v in 0..n {
+            let c = cycle[v];
+            if c != NONE && cycle_node[c] != NONE {
+                node[v] = cycle_node[c];
+                continue;
+            }
+            if c != NONE {
+                cycle_node[c] = count;
+            }
+            node[v] = count;
+            count += 1;
+        }
+        let mut next_edges = Vec::new();
+        let mut next_parent = Vec::new();
+        for (i, (u, v, w)) in edges.iter().enumerate() {
+            if node[*u] != node[*v] {
+                let reduced = w.clone() - edges[cheapest[*v]].2.clone();
+                next_edges.push((node[*u], node[*v], reduced));
+                next_parent.push(i);
+            }
+        }
+        levels.push(Level {
+            root,
+            edges,
+            parent,
+            cheapest,
+            cycle,
+            cycle_count,
+        });
+        edges = next_edges;
+        parent = next_parent;
+        root = node[root];
+        n = count;
+    }
+
+    // Expand the contracted cycles: each is entered by one edge of the level after it,
+    // and keeps the cheapest edges of its other nodes
+    let mut tree: Vec<usize> = Vec::new();
+    while let Some(level) = levels.pop() {
+        let mut entered = vec![NONE; level.cycle_count];
+        for &e in &tree {
+            let v = level.edges[e].1;
+            if level.cycle[v] != NONE {
+                entered[level.cycle[v]] = v;
+            }
+        }
+        for v in (0..level.cheapest.len()).filter(|&v| v != level.root) {
+            let keep = match level.cycle[v] {
+                // only the last level has nodes that are not entered by the tree
+                NONE => level.cycle_count == 0,
+                c => entered[c] != v,
+            };
+            if keep {
+                tree.push(level.cheapest[v]);
+            }
+        }
+        for e in &mut tree {
+            *e = level.parent[*e];
+        }
+    }
+    let mut tree: Vec<_> = tree.into_iter().map(EdgeIndex::new).collect();
+    tree.sort_by_key(|&e| g.raw_edges()[e.index()].target());
+    Some(tree)
+}
+
+/// \[Generic\] Compute a *spanning forest* of a graph, ignoring edge weights.
+///
+/// The input graph is treated as if undirected.
+///
+/// A depth-first search from each node that is not visited yet spans its connected
+/// component, with runtime **O(|V| + |E|)**. This is lighter than `min_spanning_tree` when
+/// the weights do not matter, for example to build a cycle basis.
+///
+/// Return the ids of the edges of the forest, in the order they were found. The forest has
+/// **|V| - c** edges, where **c** is the number of connected components in `g`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::spanning_forest;
+/// use petgraph::graph::{edge_index, UnGraph};
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (0, 2), (3, 4)]);
+///
+/// let forest = spanning_forest(&g);
+/// assert_eq!(forest.len(), 5 - 2);
+/// assert!(forest.contains(&edge_index(3)));
+/// ```
+pub fn spanning_forest<G>(g: G) -> Vec<G::EdgeId>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + Visitable + GraphProp,
+{
+    let mut visited = g.visit_map();
+    let mut forest = Vec::new();
+    let mut stack = Vec::new();
+
+    for root in g.node_identifiers() {
+        if !visited.visit(root) {
+            continue;
+        }
+        stack.push((root, undirected_edges(g, root)));
+        while let Some((node, edges)) = stack.last_mut() {
+            let node = *node;
+            let tree_edge = edges.find_map(|edge| {
+                let next = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if visited.visit(next) {
+                    Some((edge.id(), next))
+                } else {
+                    None
+                }
+            });
+            match tree_edge {
+                Some((edge, next)) => {
+                    forest.push(edge);
+                    stack.push((next, undirected_edges(g, next)));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+    forest
+}
@@ -4,21 +4,33 @@
 //! so that they are generally applicable. For now, some of these still require
 //! the `Graph` type.
 
+pub mod articulation_points;
 pub mod astar;
 pub mod bellman_ford;
+pub mod betweenness_centrality;
+pub mod bridges;
+pub mod coloring;
+pub mod cycle_basis;
 pub mod dijkstra;
 pub mod dominators;
+pub mod eulerian_path;
 pub mod feedback_arc_set;
 pub mod floyd_warshall;
 pub mod ford_fulkerson;
+pub mod incremental_connectivity;
 pub mod isomorphism;
+pub mod johnson;
+pub mod k_core;
 pub mod k_shortest_path;
 pub mod matching;
 pub mod min_spanning_tree;
 pub mod page_rank;
 pub mod simple_paths;
+pub mod stoer_wagner;
 pub mod tred;
 
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::num::NonZeroUsize;
 
 use crate::prelude::*;
@@ -26,27 +38,40 @@ use crate::prelude::*;
 use super::graph::IndexType;
 use super::unionfind::UnionFind;
 use super::visit::{
-    GraphBase, GraphRef, IntoEdgeReferences, IntoNeighbors, IntoNeighborsDirected,
-    IntoNodeIdentifiers, NodeCompactIndexable, NodeIndexable, Reversed, VisitMap, Visitable,
+    GraphBase, GraphProp, GraphRef, IntoEdgeReferences, IntoEdgesDirected, IntoNeighbors,
+    IntoNeighborsDirected, IntoNodeIdentifiers, NodeCompactIndexable, NodeIndexable, Reversed,
+    VisitMap, Visitable,
 };
 use super::EdgeType;
 use crate::visit::Walker;
 
+pub use articulation_points::articulation_points;
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
+pub use betweenness_centrality::betweenness_centrality;
+pub use bridges::bridges;
+pub use coloring::greedy_coloring;
+pub use cycle_basis::cycle_basis;
 pub use dijkstra::dijkstra;
+pub use eulerian_path::eulerian_path;
 pub use feedback_arc_set::greedy_feedback_arc_set;
 pub use floyd_warshall::floyd_warshall;
-pub use ford_fulkerson::ford_fulkerson;
+pub use ford_fulkerson::{ford_fulkerson, min_cut};
+pub use incremental_connectivity::IncrementalConnectivity;
 pub use isomorphism::{
     is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
     subgraph_isomorphisms_iter,
 };
+pub use johnson::johnson;
+pub use k_core::k_core;
 pub use k_shortest_path::k_shortest_path;
-pub use matching::{greedy_matching, maximum_matching, Matching};
-pub use min_spanning_tree::min_spanning_tree;
-pub use page_rank::page_rank;
+pub use matching::{greedy_matching, maximum_bipartite_matching, maximum_matching, Matching};
+pub use min_spanning_tree::{
+    min_spanning_arborescence, min_spanning_tree, prim_mst, spanning_forest,
+};
+pub use page_rank::{page_rank, page_rank_with_tolerance};
 pub use simple_paths::all_simple_paths;
+pub use stoer_wagner::stoer_wagner_min_cut;
 
 /// \[Generic\] Return the number of connected components of the graph.
 ///
@@ -87,6 +112,72 @@ pub use simple_paths::all_simple_paths;
 /// assert_eq!(connected_components(&graph),1);
 /// ```
 pub fn connected_components<G>(g: G) -> usize
+where
+    G: NodeCompactIndexable + IntoEdgeReferences,
+{
+    union_find(g).set_count()
+}
+
+/// \[Generic\] Label each node with the number of its weakly connected component.
+///
+/// Edge directions are ignored. The returned vector is indexed by the compact node
+/// index (`g.to_index(node)`), and two nodes have the same label if and only if they
+/// are in the same component. The labels are numbered densely from zero, in order of
+/// the lowest node index of each component, so the number of components is one more
+/// than the largest label.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::weakly_connected_components;
+///
+/// let mut graph = Graph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(b, a), (d, c)]);
+///
+/// assert_eq!(weakly_connected_components(&graph), vec![0, 0, 1, 1]);
+/// ```
+pub fn weakly_connected_components<G>(g: G) -> Vec<usize>
+where
+    G: NodeCompactIndexable + IntoEdgeReferences,
+{
+    union_find(g).relabel().0
+}
+
+/// \[Generic\] Return the union-find structure of the connected components of the graph.
+///
+/// Edge directions are ignored. The elements are the compact node indices
+/// (`g.to_index(node)`), and the sets are the weakly connected components: two nodes are
+/// `equiv` if and only if there is an undirected path between them. This is the structure
+/// that [`connected_components`] and [`weakly_connected_components`] summarize, to query
+/// further or to extend when edges are added.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::union_find;
+///
+/// let mut graph = Graph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(b, a), (d, c)]);
+///
+/// let mut components = union_find(&graph);
+/// assert!(components.equiv(a.index(), b.index()));
+/// assert!(!components.equiv(a.index(), c.index()));
+/// assert_eq!(components.set_count(), 2);
+///
+/// // an edge added later joins the components
+/// components.union(b.index(), c.index());
+/// assert!(components.equiv(a.index(), d.index()));
+/// assert_eq!(components.set_count(), 1);
+/// ```
+pub fn union_find<G>(g: G) -> UnionFind<usize>
 where
     G: NodeCompactIndexable + IntoEdgeReferences,
 {
@@ -97,15 +188,13 @@ where
         // union the two vertices of the edge
         vertex_sets.union(g.to_index(a), g.to_index(b));
     }
-    let mut labels = vertex_sets.into_labeling();
-    labels.sort_unstable();
-    labels.dedup();
-    labels.len()
+    vertex_sets
 }
 
 /// \[Generic\] Return `true` if the input graph contains a cycle.
 ///
-/// Always treats the input graph as if undirected.
+/// Always treats the input graph as if undirected. A self loop, or two edges
+/// connecting the same pair of nodes (in either direction), are cycles too.
 pub fn is_cyclic_undirected<G>(g: G) -> bool
 where
     G: NodeIndexable + IntoEdgeReferences,
@@ -259,6 +348,26 @@ where
     f(dfs)
 }
 
+/// Return the edges of `node` as if the graph was undirected, each edge once.
+fn undirected_edges<G>(g: G, node: G::NodeId) -> impl Iterator<Item = G::EdgeRef>
+where
+    G: IntoEdgesDirected + GraphProp,
+{
+    // Undirected graphs list every edge of a node as outgoing; in directed graphs, a self
+    // loop is also incoming, and is only listed as outgoing
+    let incoming = if g.is_directed() {
+        Some(g.edges_directed(node, Incoming))
+    } else {
+        None
+    };
+    g.edges_directed(node, Outgoing).chain(
+        incoming
+            .into_iter()
+            .flatten()
+            .filter(|edge| edge.source() != edge.target()),
+    )
+}
+
 /// \[Generic\] Check if there exists a path starting at `from` and reaching `to`.
 ///
 /// If `from` and `to` are equal, this function returns true.
@@ -281,6 +390,86 @@ where
     })
 }
 
+/// Return the path to `target` given the predecessor of each node on its path.
+///
+/// `predecessors` maps each node, except the start of the paths, to the node before
+/// it. The path is found by following it back from `target`, and is returned from
+/// the start to `target`.
+///
+/// Return `None` if `target` has no predecessor, which is the case if it is the start
+/// itself or is not reachable, or if the predecessors form a cycle.
+///
+/// See [`build_path_from_predecessors()`] for the predecessors found by [`bellman_ford()`].
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::build_path;
+/// use std::collections::HashMap;
+///
+/// let predecessors: HashMap<_, _> = vec![(1, 0), (2, 1), (3, 1)].into_iter().collect();
+/// assert_eq!(build_path(&predecessors, 2), Some(vec![0, 1, 2]));
+/// assert_eq!(build_path(&predecessors, 0), None);
+/// ```
+pub fn build_path<N>(predecessors: &HashMap<N, N>, target: N) -> Option<Vec<N>>
+where
+    N: Copy + Eq + Hash,
+{
+    let mut path = vec![target];
+    let mut node = *predecessors.get(&target)?;
+    path.push(node);
+    while let Some(&pred) = predecessors.get(&node) {
+        // a path visits each node with a predecessor at most once
+        if path.len() > predecessors.len() {
+            return None;
+        }
+        path.push(pred);
+        node = pred;
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Return the path to `target` given the predecessor of each node on its path, indexed by
+/// `g.to_index(node)`, as in the [`Paths`](bellman_ford::Paths) found by [`bellman_ford()`].
+///
+/// As [`build_path()`], return `None` if `target` has no predecessor, which is the case if it
+/// is the start itself or is not reachable, or if the predecessors form a cycle.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{bellman_ford, build_path_from_predecessors};
+/// use petgraph::graph::{node_index, Graph};
+///
+/// let g = Graph::<(), f32>::from_edges(&[(0, 1, 2.0), (1, 2, 1.0), (0, 2, 4.0)]);
+/// let paths = bellman_ford(&g, node_index(0)).unwrap();
+/// assert_eq!(
+///     build_path_from_predecessors(&g, &paths.predecessors, node_index(2)),
+///     Some(vec![node_index(0), node_index(1), node_index(2)])
+/// );
+/// ```
+pub fn build_path_from_predecessors<G>(
+    g: G,
+    predecessors: &[Option<G::NodeId>],
+    target: G::NodeId,
+) -> Option<Vec<G::NodeId>>
+where
+    G: NodeIndexable,
+{
+    let mut path = vec![target];
+    let mut node = (*predecessors.get(g.to_index(target))?)?;
+    path.push(node);
+    while let Some(&Some(pred)) = predecessors.get(g.to_index(node)) {
+        // a path visits each node with a predecessor at most once
+        if path.len() > predecessors.len() {
+            return None;
+        }
+        path.push(pred);
+        node = pred;
+    }
+    path.reverse();
+    Some(path)
+}
+
 /// Renamed to `kosaraju_scc`.
 #[deprecated(note = "renamed to kosaraju_scc")]
 pub fn scc<G>(g: G) -> Vec<Vec<G::NodeId>>
@@ -389,7 +578,7 @@ impl<N> TarjanScc<N> {
     ///
     /// For an undirected graph, the sccs are simply the connected components.
     ///
-    /// This implementation is recursive and does one pass over the nodes.
+    /// This implementation is iterative and does one pass over the nodes.
     pub fn run<G, F>(&mut self, g: G, mut f: F)
     where
         G: IntoNodeIdentifiers<NodeId = N> + IntoNeighbors<NodeId = N> + NodeIndexable<NodeId = N>,
@@ -400,17 +589,22 @@ impl<N> TarjanScc<N> {
         self.nodes
             .resize(g.node_bound(), NodeData { rootindex: None });
 
+        let mut dfs = Vec::new();
         for n in g.node_identifiers() {
             let visited = self.nodes[g.to_index(n)].rootindex.is_some();
             if !visited {
-                self.visit(n, g, &mut f);
+                self.visit(n, g, &mut f, &mut dfs);
             }
         }
 
         debug_assert!(self.stack.is_empty());
     }
 
-    fn visit<G, F>(&mut self, v: G::NodeId, g: G, f: &mut F)
+    // Visit all unvisited nodes reachable from `v` in depth-first order.
+    //
+    // `dfs` holds the path from `v` to the current node. Each entry is a node, its
+    // remaining neighbors and whether the node is still believed to be a local root.
+    fn visit<G, F>(&mut self, v: G::NodeId, g: G, f: &mut F, dfs: &mut Vec<(N, G::Neighbors, bool)>)
     where
         G: IntoNeighbors<NodeId = N> + NodeIndexable<NodeId = N>,
         F: FnMut(&[N]),
@@ -422,24 +616,54 @@ impl<N> TarjanScc<N> {
             };
         }
 
-        let node_v = &mut node![v];
-        debug_assert!(node_v.rootindex.is_none());
-
-        let mut v_is_local_root = true;
-        let v_index = self.index;
-        node_v.rootindex = NonZeroUsize::new(v_index);
-        self.index += 1;
-
-        for w in g.neighbors(v) {
+        debug_assert!(dfs.is_empty());
+        self.begin_visit(v, g);
+        dfs.push((v, g.neighbors(v), true));
+
+        while let Some(&mut (v, ref mut neighbors, _)) = dfs.last_mut() {
+            let w = match neighbors.next() {
+                Some(w) => w,
+                None => {
+                    let (v, _, v_is_local_root) = dfs.pop().unwrap();
+                    self.finish_visit(v, v_is_local_root, g, f);
+                    // Resume the parent with its neighbor `v`
+                    if let Some(&mut (u, _, ref mut u_is_local_root)) = dfs.last_mut() {
+                        if node![v].rootindex < node![u].rootindex {
+                            node![u].rootindex = node![v].rootindex;
+                            *u_is_local_root = false;
+                        }
+                    }
+                    continue;
+                }
+            };
             if node![w].rootindex.is_none() {
-                self.visit(w, g, f);
-            }
-            if node![w].rootindex < node![v].rootindex {
+                // Descend into `w`, which is compared with `v` once it is finished
+                self.begin_visit(w, g);
+                dfs.push((w, g.neighbors(w), true));
+            } else if node![w].rootindex < node![v].rootindex {
                 node![v].rootindex = node![w].rootindex;
-                v_is_local_root = false
+                dfs.last_mut().unwrap().2 = false;
             }
         }
+    }
+
+    fn begin_visit<G>(&mut self, v: N, g: G)
+    where
+        G: NodeIndexable<NodeId = N>,
+    {
+        let node_v = &mut self.nodes[g.to_index(v)];
+        debug_assert!(node_v.rootindex.is_none());
+
+        node_v.rootindex = NonZeroUsize::new(self.index);
+        self.index += 1;
+    }
 
+    fn finish_visit<G, F>(&mut self, v: N, v_is_local_root: bool, g: G, f: &mut F)
+    where
+        G: NodeIndexable<NodeId = N>,
+        F: FnMut(&[N]),
+        N: Copy,
+    {
         if v_is_local_root {
             // Pop the stack and generate an SCC.
             let mut indexadjustment = 1;
@@ -503,7 +727,7 @@ impl<N> TarjanScc<N> {
 ///
 /// For an undirected graph, the sccs are simply the connected components.
 ///
-/// This implementation is recursive and does one pass over the nodes. It is based on
+/// This implementation is iterative and does one pass over the nodes. It is based on
 /// [A Space-Efficient Algorithm for Finding Strongly Connected Components][2] by David J. Pierce,
 /// to provide a memory-efficient implementation of [Tarjan's algorithm][1].
 pub fn tarjan_scc<G>(g: G) -> Vec<Vec<G::NodeId>>
@@ -518,6 +742,40 @@ where
     sccs
 }
 
+/// \[Generic\] Label each node with the number of its strongly connected component.
+///
+/// Return a vector indexed by the compact node index (`g.to_index(node)`), with the numbers
+/// of the components, and the number of components *k*. The components are numbered densely,
+/// from *0* to *k - 1*, in the order of [`tarjan_scc`]: that is in reverse topological order,
+/// so every edge goes from a component to itself or to one with a lower number.
+///
+/// For an undirected graph, the sccs are simply the connected components.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::scc_labeling;
+///
+/// // the cycle 0 -> 1 -> 2 -> 0, then 2 -> 3
+/// let graph = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+///
+/// assert_eq!(scc_labeling(&graph), (vec![1, 1, 1, 0], 2));
+/// ```
+pub fn scc_labeling<G>(g: G) -> (Vec<usize>, usize)
+where
+    G: IntoNodeIdentifiers + IntoNeighbors + NodeCompactIndexable,
+{
+    let mut labels = vec![0; g.node_bound()];
+    let mut count = 0;
+    TarjanScc::new().run(g, |scc| {
+        for &node in scc {
+            labels[g.to_index(node)] = count;
+        }
+        count += 1;
+    });
+    (labels, count)
+}
+
 /// [Graph] Condense every strongly connected component into a single node and return the result.
 ///
 /// If `make_acyclic` is true, self-loops and multi edges are ignored, guaranteeing that
@@ -710,10 +968,74 @@ where
     true
 }
 
+/// \[Generic\] Divide the nodes of a graph into two sides, such that every edge connects
+/// the two sides, if that is possible.
+///
+/// Always treats the input graph as if undirected. Each connected component is 2-colored
+/// with a breadth-first search, starting with its first node in `g.node_identifiers()`
+/// on the `false` side.
+///
+/// Return a vector indexed by the node index, with the side of each node, or `None` if the
+/// graph is not bipartite because it has a cycle of odd length. A self loop is such a cycle.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bipartite_coloring;
+/// use petgraph::graph::UnGraph;
+///
+/// let square = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// assert_eq!(bipartite_coloring(&square), Some(vec![false, true, false, true]));
+///
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert_eq!(bipartite_coloring(&triangle), None);
+/// ```
+pub fn bipartite_coloring<G>(g: G) -> Option<Vec<bool>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let mut side = vec![None; g.node_bound()];
+    let mut queue = ::std::collections::VecDeque::new();
+
+    for start in g.node_identifiers() {
+        if side[g.to_index(start)].is_some() {
+            continue;
+        }
+        side[g.to_index(start)] = Some(false);
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            let node_side = side[g.to_index(node)];
+            for edge in undirected_edges(g, node) {
+                let next = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                match side[g.to_index(next)] {
+                    None => {
+                        side[g.to_index(next)] = node_side.map(|s| !s);
+                        queue.push_back(next);
+                    }
+                    Some(next_side) if Some(next_side) == node_side => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    // Indices that are not in use by a node are never visited
+    Some(side.into_iter().map(|s| s == Some(true)).collect())
+}
+
 use std::fmt::Debug;
 use std::ops::Add;
 
 /// Associated data that can be used for measures (such as length).
+///
+/// This is the bound on the costs of the path algorithms like [`dijkstra()`] and [`astar()`]:
+/// `Default` is the zero cost of an empty path, `Add` extends a path, and `PartialOrd`
+/// compares paths. It is implemented for every type with these traits, so a custom cost type,
+/// like a saturating integer, only needs to implement them.
 pub trait Measure: Debug + PartialOrd + Add<Self, Output = Self> + Default + Clone {}
 
 impl<M> Measure for M where M: Debug + PartialOrd + Add<M, Output = M> + Default + Clone {}
@@ -742,9 +1064,14 @@ impl FloatMeasure for f64 {
     }
 }
 
+/// A measure with bounds, for the algorithms like [`floyd_warshall()`] that need a cost
+/// for unreachable nodes.
 pub trait BoundedMeasure: Measure + std::ops::Sub<Self, Output = Self> {
+    /// The least value.
     fn min() -> Self;
+    /// The greatest value, which stands for infinity.
     fn max() -> Self;
+    /// Return the sum, and whether it overflowed the bounds.
     fn overflowing_add(self, rhs: Self) -> (Self, bool);
 }
 
@@ -1,4 +1,4 @@
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable};
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable};
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
@@ -96,6 +96,108 @@ where
     ranks
 }
 
+/// \[Generic\] Page Rank algorithm, iterated until the ranks converge.
+///
+/// Computes the ranks of every node in a graph using the [Page Rank algorithm][pr], by power
+/// iteration: in each step, a node passes `damping_factor` of its rank to its successors,
+/// shared equally among its outgoing edges, and the rest is spread over all nodes. A node
+/// without outgoing edges spreads all of its rank over all nodes.
+///
+/// Stops after `max_iter` iterations, or as soon as the ranks change by less than `tolerance`
+/// in total (the L1 norm of the change).
+///
+/// Returns a `Vec` container mapping each node index to its rank, indexed by
+/// `graph.to_index(node)`, of length `graph.node_bound()`. The ranks sum to 1, and indices
+/// that belong to no node have rank 0.
+///
+/// # Panics
+/// The damping factor should be a number of type `f32` or `f64` strictly between 0 and 1. Otherwise, it panics.
+///
+/// # Complexity
+/// Time complexity is **O(N(|V| + |E|))**.
+/// Space complexity is **O(|V|)**
+/// where **N** is the number of iterations, **|V|** the number of vertices (i.e nodes) and **|E|** the number of edges.
+///
+/// [pr]: https://en.wikipedia.org/wiki/PageRank
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::page_rank_with_tolerance;
+///
+/// // 0 and 1 link to each other, and 2 links to 0
+/// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 0), (2, 0)]);
+/// let ranks = page_rank_with_tolerance(&g, 0.85_f64, 1e-12, 1000);
+///
+/// // r2 = 0.15 / 3, r0 = r2 + 0.85 (r1 + r2), r1 = r2 + 0.85 r0
+/// let expected = [0.135 / 0.2775, 0.05 + 0.85 * 0.135 / 0.2775, 0.05];
+/// for (rank, expected) in ranks.iter().zip(&expected) {
+///     assert!((rank - expected).abs() < 1e-9);
+/// }
+/// ```
+pub fn page_rank_with_tolerance<G, D>(
+    graph: G,
+    damping_factor: D,
+    tolerance: D,
+    max_iter: usize,
+) -> Vec<D>
+where
+    G: NodeCount + IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy,
+{
+    assert!(
+        D::zero() < damping_factor && damping_factor < D::one(),
+        "Damping factor should be strictly between 0 and 1."
+    );
+    let node_count = graph.node_count();
+    if node_count == 0 {
+        return vec![];
+    }
+    let nb = D::from_usize(node_count);
+    let ix = |n| graph.to_index(n);
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut out_degrees = vec![D::zero(); graph.node_bound()];
+    for &u in &nodes {
+        out_degrees[ix(u)] = graph.edges(u).map(|_| D::one()).sum::<D>();
+    }
+
+    let mut ranks = vec![D::zero(); graph.node_bound()];
+    for &u in &nodes {
+        ranks[ix(u)] = D::one() / nb;
+    }
+    let mut next = vec![D::zero(); graph.node_bound()];
+    for _ in 0..max_iter {
+        let mut dangling = D::zero();
+        for &u in &nodes {
+            let w = ix(u);
+            if out_degrees[w] == D::zero() {
+                dangling = dangling + ranks[w];
+            } else {
+                let share = damping_factor * ranks[w] / out_degrees[w];
+                for edge in graph.edges(u) {
+                    let v = ix(edge.target());
+                    next[v] = next[v] + share;
+                }
+            }
+        }
+        let spread = ((D::one() - damping_factor) + damping_factor * dangling) / nb;
+        let mut change = D::zero();
+        for &u in &nodes {
+            let (new, old) = (next[ix(u)] + spread, ranks[ix(u)]);
+            next[ix(u)] = new;
+            change = change + if new > old { new - old } else { old - new };
+        }
+        std::mem::swap(&mut ranks, &mut next);
+        if change < tolerance {
+            break;
+        }
+        for rank in &mut next {
+            *rank = D::zero();
+        }
+    }
+    ranks
+}
+
 #[allow(dead_code)]
 fn out_edges_info<G, D>(graph: G, index_w: usize, index_v: usize) -> (D, bool)
 where
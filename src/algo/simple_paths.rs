@@ -13,6 +13,10 @@ use crate::{
 /// Returns an iterator that produces all simple paths from `from` node to `to`, which contains at least `min_intermediate_nodes` nodes
 /// and at most `max_intermediate_nodes`, if given, or limited by the graph's order otherwise. The simple path is a path without repetitions.
 ///
+/// Paths are produced lazily, by a depth-first search that stops descending once the path
+/// reaches the maximum length. If `from` and `to` are the same node, there are no paths:
+/// a path back to `from` would repeat it.
+///
 /// This algorithm is adapted from <https://networkx.github.io/documentation/stable/reference/algorithms/generated/networkx.algorithms.simple_paths.all_simple_paths.html>.
 ///
 /// # Example
@@ -61,7 +65,11 @@ where
     let mut visited: IndexSet<G::NodeId> = IndexSet::from_iter(Some(from));
     // list of childs of currently exploring path nodes,
     // last elem is list of childs of last visited node
-    let mut stack = vec![graph.neighbors_directed(from, Outgoing)];
+    let mut stack = if from == to {
+        vec![]
+    } else {
+        vec![graph.neighbors_directed(from, Outgoing)]
+    };
 
     from_fn(move || {
         while let Some(children) = stack.last_mut() {
@@ -167,6 +175,35 @@ mod test {
         assert_equal(expexted_simple_paths_0_to_1, &actual_simple_paths_0_to_1);
     }
 
+    #[test]
+    fn test_diamond_simple_paths() {
+        //   1
+        //  / \
+        // 0   3 - 4
+        //  \ /
+        //   2
+        let graph =
+            DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (1, 2)]);
+        let count = |from: u32, to: u32, min, max| {
+            all_simple_paths::<Vec<_>, _>(&graph, from.into(), to.into(), min, max).count()
+        };
+        // 0-1-3, 0-2-3, 0-1-2-3
+        assert_eq!(count(0, 3, 0, None), 3);
+        assert_eq!(count(0, 3, 2, None), 1);
+        assert_eq!(count(0, 3, 0, Some(1)), 2);
+        assert_eq!(count(0, 4, 0, Some(2)), 2);
+        assert_eq!(count(0, 4, 0, Some(1)), 0);
+        assert_eq!(count(3, 0, 0, None), 0);
+        assert_eq!(count(0, 0, 0, None), 0);
+
+        // a path back to the source is a cycle, not a simple path
+        let cycle = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 0)]);
+        assert_eq!(
+            all_simple_paths::<Vec<_>, _>(&cycle, 0u32.into(), 0u32.into(), 0, Some(1)).count(),
+            0
+        );
+    }
+
     #[test]
     fn test_no_simple_paths() {
         let graph = DiGraph::<i32, i32, _>::from_edges(&[(0, 1), (2, 1)]);
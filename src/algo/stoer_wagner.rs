@@ -0,0 +1,123 @@
+//! Global minimum cut.
+
+use super::Measure;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Global minimum cut of a weighted undirected graph.
+///
+/// Computes a set of edges of least total weight whose removal disconnects the graph, with
+/// the weight of each edge given by `edge_cost`. Edge directions are ignored. The weights must
+/// not be negative.
+///
+/// Using the Stoer–Wagner algorithm, with runtime **O(|V|³)** and **O(|V|²)** space: each
+/// phase adds the nodes one by one, always the one most tightly connected to those already
+/// added. The weight of the edges of the last node is the minimum cut between it and the node
+/// added before it, which are then merged into one node for the next phase.
+///
+/// Returns the weight of the cut and the nodes of one of its sides, in the order of
+/// `node_identifiers`, or `None` if the graph has fewer than two nodes. A disconnected graph
+/// has a cut of zero weight.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::stoer_wagner_min_cut;
+/// use petgraph::graph::{node_index, UnGraph};
+/// use petgraph::visit::EdgeRef;
+///
+/// // Two triangles, joined by an edge of weight 1
+/// let graph = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 3),
+///     (1, 2, 3),
+///     (2, 0, 3),
+///     (3, 4, 2),
+///     (4, 5, 2),
+///     (5, 3, 2),
+///     (2, 3, 1),
+/// ]);
+/// let (weight, side) = stoer_wagner_min_cut(&graph, |edge| *edge.weight()).unwrap();
+/// assert_eq!(weight, 1);
+/// assert!(side == vec![node_index(0), node_index(1), node_index(2)]
+///     || side == vec![node_index(3), node_index(4), node_index(5)]);
+/// ```
+pub fn stoer_wagner_min_cut<G, F, K>(g: G, mut edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let nodes: Vec<_> = g.node_identifiers().collect();
+    let n = nodes.len();
+    if n < 2 {
+        return None;
+    }
+    // the position of each node in `nodes`
+    let mut position = vec![0; g.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        position[g.to_index(node)] = i;
+    }
+
+    let mut weight = vec![vec![K::default(); n]; n];
+    for edge in g.edge_references() {
+        let a = position[g.to_index(edge.source())];
+        let b = position[g.to_index(edge.target())];
+        let cost = edge_cost(edge);
+        debug_assert!(cost >= K::default(), "negative edge weight");
+        if a != b {
+            weight[a][b] = weight[a][b] + cost;
+            weight[b][a] = weight[b][a] + cost;
+        }
+    }
+
+    // the original nodes merged into each node
+    let mut merged: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best: Option<(K, Vec<usize>)> = None;
+    let mut connection = vec![K::default(); n];
+    let mut added = vec![false; n];
+    while active.len() > 1 {
+        for &v in &active {
+            connection[v] = K::default();
+            added[v] = false;
+        }
+        let mut previous = active[0];
+        let mut last = active[0];
+        for _ in 0..active.len() {
+            let mut next = None;
+            for &v in &active {
+                if !added[v] && next.map_or(true, |u| connection[v] > connection[u]) {
+                    next = Some(v);
+                }
+            }
+            let next = next.unwrap();
+            added[next] = true;
+            previous = last;
+            last = next;
+            for &v in &active {
+                if !added[v] {
+                    connection[v] = connection[v] + weight[next][v];
+                }
+            }
+        }
+
+        // the cut of the phase separates `last` from all the other nodes
+        let cut = connection[last];
+        if best.as_ref().map_or(true, |(best, _)| cut < *best) {
+            best = Some((cut, merged[last].clone()));
+        }
+
+        for &v in &active {
+            if v != previous && v != last {
+                weight[previous][v] = weight[previous][v] + weight[last][v];
+                weight[v][previous] = weight[previous][v];
+            }
+        }
+        let members = std::mem::take(&mut merged[last]);
+        merged[previous].extend(members);
+        active.retain(|&v| v != last);
+    }
+
+    best.map(|(cut, mut side)| {
+        side.sort_unstable();
+        (cut, side.into_iter().map(|i| nodes[i]).collect())
+    })
+}
@@ -6,12 +6,15 @@
 //! **i** to **j** in **G**. The *transitive reduction* of **G** is the graph **Gr
 //! = (V, Er)** such that **Er** is minimal wrt. inclusion in **E** and the transitive
 //! closure of **Gr** is the same as that of **G**.
-//! The transitive reduction is well-defined for acyclic graphs only.
+//! The transitive reduction is well-defined for acyclic graphs only, the transitive closure
+//! of any directed graph is computed by [`transitive_closure`].
 
+use super::tarjan_scc;
 use crate::adj::{List, UnweightedList};
 use crate::graph::IndexType;
 use crate::visit::{
-    GraphBase, IntoNeighbors, IntoNeighborsDirected, NodeCompactIndexable, NodeCount,
+    GraphBase, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCompactIndexable,
+    NodeCount, NodeIndexable,
 };
 use crate::Direction;
 use fixedbitset::FixedBitSet;
@@ -139,6 +142,88 @@ pub fn dag_transitive_reduction_closure<E, Ix: IndexType>(
     (tred, tclos)
 }
 
+/// Computes the transitive closure of a directed graph: the graph with an edge **(i, j)**
+/// whenever there is a path from **i** to **j**.
+///
+/// Unlike [`dag_transitive_reduction_closure`], the graph may have cycles, and needs no
+/// specific format.
+///
+/// A node is always reachable from itself through a path of length zero; if `reflexive` is
+/// `true`, every node has an edge to itself in the closure. Otherwise, a node only has an edge
+/// to itself if it is on a cycle, including a self loop.
+///
+/// The closure has a node for each index of `g`: the node `g.to_index(n)` of the closure is the
+/// node `n` of `g`, and the neighbors of each node are sorted. Vacant indices of `g` are nodes
+/// without edges.
+///
+/// The strongly connected components are computed with [`tarjan_scc`], then the nodes
+/// reachable from each component are accumulated in a bitset, in reverse topological order.
+///
+/// Runtime complexity: **O(|V| + |E| + |V|·|Ec| / w)**, where **|Ec|** is the number of edges
+/// between components and **w** is the word size, plus **O(|V|²)** to build the closure.
+///
+/// Space complexity: **O(|V|²)**.
+///
+/// ```
+/// use petgraph::adj::UnweightedList;
+/// use petgraph::prelude::*;
+/// use petgraph::algo::tred::transitive_closure;
+///
+/// // a cycle 0 -> 1 -> 0, reaching 2
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 0), (1, 2)]);
+/// let tclos: UnweightedList<u32> = transitive_closure(&g, false);
+/// assert!(tclos.contains_edge(0, 0));
+/// assert!(tclos.contains_edge(0, 2));
+/// assert!(!tclos.contains_edge(2, 2));
+/// let tclos: UnweightedList<u32> = transitive_closure(&g, true);
+/// assert!(tclos.contains_edge(2, 2));
+/// ```
+pub fn transitive_closure<G, Ix>(g: G, reflexive: bool) -> UnweightedList<Ix>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    Ix: IndexType,
+{
+    let ix = |n| g.to_index(n);
+    // the components come in reverse topological order,
+    // so the components reachable from one come before it
+    let sccs = tarjan_scc(g);
+    let mut component = vec![0; g.node_bound()];
+    for (c, scc) in sccs.iter().enumerate() {
+        for &n in scc {
+            component[ix(n)] = c;
+        }
+    }
+    // the nodes reachable from each component, including its own
+    let mut reach: Vec<FixedBitSet> = Vec::with_capacity(sccs.len());
+    for (c, scc) in sccs.iter().enumerate() {
+        let mut nodes = FixedBitSet::with_capacity(g.node_bound());
+        for &n in scc {
+            nodes.insert(ix(n));
+            for m in g.neighbors(n) {
+                if component[ix(m)] != c {
+                    nodes.union_with(&reach[component[ix(m)]]);
+                }
+            }
+        }
+        reach.push(nodes);
+    }
+
+    let mut tclos = List::with_capacity(g.node_bound());
+    for _ in 0..g.node_bound() {
+        tclos.add_node();
+    }
+    for n in g.node_identifiers() {
+        let c = component[ix(n)];
+        let cyclic = sccs[c].len() > 1 || g.neighbors(n).any(|m| m == n);
+        for m in reach[c].ones() {
+            if m != ix(n) || reflexive || cyclic {
+                tclos.add_edge(Ix::new(ix(n)), Ix::new(m), ());
+            }
+        }
+    }
+    tclos
+}
+
 #[cfg(test)]
 #[test]
 fn test_easy_tred() {
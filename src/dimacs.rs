@@ -0,0 +1,301 @@
+//! Simple DIMACS graph file format input and output.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use crate::graph::{node_index, DefaultIx, Graph, IndexType};
+use crate::visit::{EdgeCount, EdgeRef, GraphProp, IntoEdgeReferences, NodeCompactIndexable};
+use crate::EdgeType;
+
+/// The largest number of nodes without edges that `from_dimacs` accepts.
+const MAX_ISOLATED_NODES: usize = 1 << 20;
+
+/// Read a graph in the DIMACS format, as used by the DIMACS implementation challenges.
+///
+/// The lines are:
+///
+/// - `c ...`: a comment, which is ignored, like empty lines.
+/// - `p format n m`: the problem line, before any edge, with the number of nodes `n` and of
+///   edges `m`. The format is usually `edge` for an undirected graph and `sp` for a
+///   directed one, but it is not checked.
+/// - `e u v`: an edge from node `u` to node `v`, of weight `1`; or `e u v w`, of weight `w`.
+/// - `a u v w`: an edge (an *arc*) from node `u` to node `v`, of weight `w`.
+///
+/// The nodes are numbered from `1` to `n` in the file, and from `0` to `n - 1` in the graph.
+/// The graph is directed or undirected depending on `Ty`.
+///
+/// Return an error of kind `InvalidData`, with the number of the line, if a line can not be
+/// parsed, or a node is not between `1` and `n`; and an error if the number of edges is not `m`,
+/// or if more than 2²⁰ nodes have no edges, so that the memory used is bounded by the size of
+/// the input. Return any error of `reader`.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::dimacs::from_dimacs;
+/// use petgraph::graph::{node_index, DiGraph};
+/// use petgraph::Directed;
+///
+/// let input = "c a path\np sp 3 2\na 1 2 10\na 2 3 -4\n";
+/// let graph: DiGraph<(), i64> = from_dimacs::<Directed, _>(input.as_bytes()).unwrap();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_weights().sum::<i64>(), 6);
+/// assert!(graph.contains_edge(node_index(0), node_index(1)));
+/// ```
+pub fn from_dimacs<Ty, R>(reader: R) -> io::Result<Graph<(), i64, Ty>>
+where
+    Ty: EdgeType,
+    R: Read,
+{
+    // the counts of the problem line are only checked at the end, so the edges are read
+    // first, instead of trusting them to allocate the graph
+    let mut edges = Vec::new();
+    let mut nodes = HashSet::new();
+    // the declared numbers of nodes and edges, from the problem line
+    let mut problem: Option<(usize, usize)> = None;
+    for (number, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        let error = |message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}: {:?}", number + 1, message, line),
+            )
+        };
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let fields: Vec<_> = fields.collect();
+        match kind {
+            None | Some("c") => continue,
+            Some("p") => {
+                if problem.is_some() {
+                    return Err(error("more than one problem line"));
+                }
+                let count =
+                    |field: &str| field.parse::<usize>().map_err(|_| error("invalid count"));
+                let (n, m) = match fields[..] {
+                    [_, n, m] => (count(n)?, count(m)?),
+                    _ => return Err(error("expected `p format nodes edges`")),
+                };
+                if n >= <DefaultIx as IndexType>::max().index() {
+                    return Err(error("too many nodes"));
+                }
+                problem = Some((n, m));
+            }
+            Some(kind @ "e") | Some(kind @ "a") => {
+                let (n, _) = problem.ok_or_else(|| error("edge before the problem line"))?;
+                let node = |field: &str| match field.parse::<usize>() {
+                    Ok(i) if (1..=n).contains(&i) => Ok(i - 1),
+                    Ok(_) => Err(error("node out of range")),
+                    Err(_) => Err(error("invalid node")),
+                };
+                let weight =
+                    |field: &str| field.parse::<i64>().map_err(|_| error("invalid weight"));
+                let (u, v, w) = match (kind, &fields[..]) {
+                    ("e", &[u, v]) => (node(u)?, node(v)?, 1),
+                    (_, &[u, v, w]) => (node(u)?, node(v)?, weight(w)?),
+                    ("e", _) => return Err(error("expected `e u v` or `e u v w`")),
+                    _ => return Err(error("expected `a u v w`")),
+                };
+                nodes.insert(u);
+                nodes.insert(v);
+                edges.push((u, v, w));
+            }
+            Some(_) => return Err(error("unknown line")),
+        }
+    }
+    let invalid = |message: String| Err(io::Error::new(io::ErrorKind::InvalidData, message));
+    let (n, m) = match problem {
+        Some(problem) => problem,
+        None => return invalid("no problem line".to_owned()),
+    };
+    if m != edges.len() {
+        return invalid(format!(
+            "expected {} edges from the problem line, found {}",
+            m,
+            edges.len()
+        ));
+    }
+    if n - nodes.len() > MAX_ISOLATED_NODES {
+        return invalid(format!(
+            "{} nodes without edges, more than {}",
+            n - nodes.len(),
+            MAX_ISOLATED_NODES
+        ));
+    }
+    let mut graph = Graph::with_capacity(n, m);
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for (u, v, w) in edges {
+        graph.add_edge(node_index(u), node_index(v), w);
+    }
+    Ok(graph)
+}
+
+/// Write a graph in the DIMACS format, as read by [`from_dimacs`].
+///
+/// An undirected graph is written as an `edge` problem, with a line `e u v w` for each edge,
+/// and a directed graph as an `sp` problem, with a line `a u v w` for each edge; the weights
+/// are formatted with `Display`. Nodes are numbered from `1`, so the node of index `i` is
+/// `i + 1` in the file.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::dimacs::to_dimacs;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), i64>::from_edges(&[(0, 1, 5), (1, 2, 7)]);
+/// let mut out = Vec::new();
+/// to_dimacs(&graph, &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "p edge 3 2\ne 1 2 5\ne 2 3 7\n");
+/// ```
+pub fn to_dimacs<G, W>(g: G, out: &mut W) -> io::Result<()>
+where
+    G: IntoEdgeReferences + NodeCompactIndexable + EdgeCount + GraphProp,
+    G::EdgeWeight: Display,
+    W: Write,
+{
+    let (format, kind) = if g.is_directed() {
+        ("sp", "a")
+    } else {
+        ("edge", "e")
+    };
+    writeln!(out, "p {} {} {}", format, g.node_count(), g.edge_count())?;
+    for edge in g.edge_references() {
+        writeln!(
+            out,
+            "{} {} {} {}",
+            kind,
+            g.to_index(edge.source()) + 1,
+            g.to_index(edge.target()) + 1,
+            edge.weight()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_dimacs, to_dimacs};
+    use crate::graph::{edge_index, node_index};
+    use crate::prelude::{DiGraph, UnGraph};
+    use crate::{Directed, Undirected};
+    use std::io;
+
+    #[test]
+    fn test_one_based() {
+        let input = "\
+c nodes 1 and 4 are the first and last
+p edge 4 3
+e 1 2
+e 2 4 3
+
+e 4 4 -1
+";
+        let graph: UnGraph<(), i64> = from_dimacs::<Undirected, _>(input.as_bytes()).unwrap();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(
+            graph.edge_endpoints(edge_index(0)),
+            Some((node_index(0), node_index(1)))
+        );
+        assert_eq!(
+            graph.edge_endpoints(edge_index(1)),
+            Some((node_index(1), node_index(3)))
+        );
+        assert_eq!(
+            graph.edge_endpoints(edge_index(2)),
+            Some((node_index(3), node_index(3)))
+        );
+        assert_eq!(
+            graph.edge_weights().cloned().collect::<Vec<_>>(),
+            vec![1, 3, -1]
+        );
+        assert_eq!(graph.neighbors(node_index(2)).count(), 0);
+
+        // and back to the numbering from 1
+        let mut out = Vec::new();
+        to_dimacs(&graph, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "p edge 4 3\ne 1 2 1\ne 2 4 3\ne 4 4 -1\n"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let graph = DiGraph::<(), i64>::from_edges(&[(0, 1, 2), (1, 0, -3), (2, 3, 4), (4, 0, 0)]);
+        let mut out = Vec::new();
+        to_dimacs(&graph, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("p sp 5 4\na 1 2 2\n"));
+        let read: DiGraph<(), i64> = from_dimacs::<Directed, _>(text.as_bytes()).unwrap();
+        assert_eq!(read.node_count(), graph.node_count());
+        for (a, b) in read.raw_edges().iter().zip(graph.raw_edges()) {
+            assert_eq!(a.source(), b.source());
+            assert_eq!(a.target(), b.target());
+            assert_eq!(a.weight, b.weight);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input: &str| {
+            let error = from_dimacs::<Directed, _>(input.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+            error.to_string()
+        };
+        assert_eq!(error("c nothing\n"), "no problem line");
+        assert_eq!(
+            error("a 1 2 3\n"),
+            "line 1: edge before the problem line: \"a 1 2 3\""
+        );
+        assert_eq!(
+            error("p sp 2 1\na 0 1 5\n"),
+            "line 2: node out of range: \"a 0 1 5\""
+        );
+        assert_eq!(
+            error("p sp 2 1\na 1 3 5\n"),
+            "line 2: node out of range: \"a 1 3 5\""
+        );
+        assert_eq!(
+            error("p sp 2 1\na 1 2\n"),
+            "line 2: expected `a u v w`: \"a 1 2\""
+        );
+        assert_eq!(
+            error("p edge 2 1\ne 1 2 x\n"),
+            "line 2: invalid weight: \"e 1 2 x\""
+        );
+        assert_eq!(
+            error("p sp 2\n"),
+            "line 1: expected `p format nodes edges`: \"p sp 2\""
+        );
+        assert_eq!(
+            error("p sp 2 0\np sp 2 0\n"),
+            "line 2: more than one problem line: \"p sp 2 0\""
+        );
+        assert_eq!(error("p sp 2 0\nx\n"), "line 2: unknown line: \"x\"");
+        assert_eq!(
+            error("p sp 2 2\na 1 2 1\n"),
+            "expected 2 edges from the problem line, found 1"
+        );
+        assert_eq!(
+            error("p sp 1 18446744073709551615\n"),
+            "expected 18446744073709551615 edges from the problem line, found 0"
+        );
+        assert_eq!(
+            error("p sp 4294967294 1\n"),
+            "expected 1 edges from the problem line, found 0"
+        );
+        assert_eq!(
+            error("p sp 4294967294 0\n"),
+            "4294967294 nodes without edges, more than 1048576"
+        );
+        assert_eq!(
+            error("p sp 4294967294 1\na 1 4294967294 0\n"),
+            "4294967292 nodes without edges, more than 1048576"
+        );
+    }
+}
@@ -33,16 +33,16 @@ use crate::visit::{
 /// // In this case the output looks like this:
 /// //
 /// // digraph {
-/// //     0 [label="\"A\""]
-/// //     1 [label="\"B\""]
-/// //     2 [label="\"C\""]
-/// //     3 [label="\"D\""]
-/// //     0 -> 1
-/// //     0 -> 2
-/// //     0 -> 3
-/// //     1 -> 2
-/// //     1 -> 3
-/// //     2 -> 3
+/// //     0 [ label = "\"A\"" ]
+/// //     1 [ label = "\"B\"" ]
+/// //     2 [ label = "\"C\"" ]
+/// //     3 [ label = "\"D\"" ]
+/// //     0 -> 1 [ ]
+/// //     0 -> 2 [ ]
+/// //     0 -> 3 [ ]
+/// //     1 -> 2 [ ]
+/// //     1 -> 3 [ ]
+/// //     2 -> 3 [ ]
 /// // }
 ///
 /// // If you need multiple config options, just list them all in the slice.
@@ -279,7 +279,7 @@ where
 #[cfg(test)]
 mod test {
     use super::{Config, Dot, Escaper};
-    use crate::prelude::Graph;
+    use crate::prelude::{Graph, Undirected};
     use crate::visit::NodeRef;
     use std::fmt::Write;
 
@@ -332,6 +332,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_display_undirected_escaped() {
+        let mut graph = Graph::<&str, &str, Undirected>::new_undirected();
+        let a = graph.add_node("say \"hi\"");
+        let b = graph.add_node("back\\slash");
+        graph.add_edge(a, b, "two\nlines");
+        let dot = format!("{}", Dot::new(&graph));
+        assert_eq!(
+            dot,
+            "graph {\n    0 [ label = \"say \\\"hi\\\"\" ]\n    1 [ label = \"back\\\\slash\" ]\n    0 -- 1 [ label = \"two\\llines\" ]\n}\n"
+        );
+    }
+
     #[test]
     fn test_with_attr_getters() {
         let graph = simple_graph();
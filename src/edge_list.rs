@@ -0,0 +1,154 @@
+//! Simple edge list file format input.
+
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::graph::{node_index, DefaultIx, Graph, IndexType};
+use crate::EdgeType;
+
+/// Read a graph from an edge list, as used by many collections of benchmark graphs.
+///
+/// Each line is an edge `u v`, or a weighted edge `u v w`, with the fields separated by
+/// whitespace. `u` and `v` are node indices, and the graph has a node for every index up to
+/// the largest one. The weight `w` is a floating-point number, and is `1.0` when it is absent.
+/// Empty lines, and comment lines starting with `#` or `%`, are ignored, so the body of a
+/// Matrix Market coordinate file can be read after its size line is removed.
+///
+/// The graph is directed or undirected depending on `Ty`.
+///
+/// Return an error of kind `InvalidData`, with the number of the line, if a line is not an
+/// edge; and any error of `reader`.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::edge_list::from_edge_list;
+/// use petgraph::graph::{node_index, UnGraph};
+/// use petgraph::Undirected;
+///
+/// let input = "# a triangle\n0 1\n1 2 0.5\n2 0\n";
+/// let graph: UnGraph<(), f64> = from_edge_list::<Undirected, _>(input.as_bytes()).unwrap();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 3);
+/// assert_eq!(graph.edge_weights().sum::<f64>(), 2.5);
+/// assert!(graph.contains_edge(node_index(0), node_index(2)));
+/// ```
+pub fn from_edge_list<Ty, R>(reader: R) -> io::Result<Graph<(), f64, Ty>>
+where
+    Ty: EdgeType,
+    R: Read,
+{
+    let mut graph = Graph::default();
+    for (number, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+            continue;
+        }
+        let error = |message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}: {:?}", number + 1, message, line),
+            )
+        };
+        let node = |field: Option<&str>| {
+            let index = field
+                .ok_or_else(|| error("expected two node indices"))?
+                .parse::<usize>()
+                .map_err(|_| error("invalid node index"))?;
+            if index >= <DefaultIx as IndexType>::max().index() {
+                return Err(error("node index out of range"));
+            }
+            Ok(index)
+        };
+        let mut fields = line.split_whitespace();
+        let source = node(fields.next())?;
+        let target = node(fields.next())?;
+        let weight = match fields.next() {
+            None => 1.,
+            Some(field) => field.parse().map_err(|_| error("invalid weight"))?,
+        };
+        if fields.next().is_some() {
+            return Err(error("expected at most three fields"));
+        }
+        while graph.node_count() <= source.max(target) {
+            graph.add_node(());
+        }
+        graph.add_edge(node_index(source), node_index(target), weight);
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod test {
+    use super::from_edge_list;
+    use crate::graph::{edge_index, node_index};
+    use crate::prelude::{DiGraph, UnGraph};
+    use crate::{Directed, Undirected};
+    use std::io;
+
+    #[test]
+    fn test_comments() {
+        let input = "\
+% a Matrix Market style comment
+# a path, with a gap
+0 1
+
+1\t2
+2 4
+";
+        let graph: DiGraph<(), f64> = from_edge_list::<Directed, _>(input.as_bytes()).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.contains_edge(node_index(1), node_index(2)));
+        assert!(!graph.contains_edge(node_index(2), node_index(1)));
+        assert!(graph.edge_weights().all(|&w| w == 1.));
+        assert_eq!(graph.neighbors(node_index(3)).count(), 0);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let input = "0 1 2.5\n  1 1 -1e3  \n3 0 7\n";
+        let graph: UnGraph<(), f64> = from_edge_list::<Undirected, _>(input.as_bytes()).unwrap();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph[edge_index(0)], 2.5);
+        assert_eq!(graph[edge_index(1)], -1000.);
+        assert_eq!(
+            graph.edge_endpoints(edge_index(2)),
+            Some((node_index(3), node_index(0)))
+        );
+        assert!(graph.contains_edge(node_index(0), node_index(3)));
+        assert_eq!(
+            from_edge_list::<Directed, _>(&b""[..])
+                .unwrap()
+                .node_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input: &str| {
+            let error = from_edge_list::<Directed, _>(input.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+            error.to_string()
+        };
+        assert_eq!(
+            error("0 1\n# ok\n2\n"),
+            "line 3: expected two node indices: \"2\""
+        );
+        assert_eq!(error("0 x\n"), "line 1: invalid node index: \"0 x\"");
+        assert_eq!(error("0 -1\n"), "line 1: invalid node index: \"0 -1\"");
+        assert_eq!(
+            error("0 1 heavy\n"),
+            "line 1: invalid weight: \"0 1 heavy\""
+        );
+        assert_eq!(
+            error("0 1 2 3\n"),
+            "line 1: expected at most three fields: \"0 1 2 3\""
+        );
+        assert_eq!(
+            error("0 99999999999\n"),
+            "line 1: node index out of range: \"0 99999999999\""
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::fmt;
+use std::hash::Hash;
+
+use super::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::{Directed, EdgeType};
+
+/// `GraphBuilder` assembles a `Graph` whose nodes are identified by keys of type `K`, such as
+/// the names or ids of an edge list being imported.
+///
+/// Each key is given a node the first time it is used, and the same node afterwards.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::GraphBuilder;
+///
+/// let mut builder = GraphBuilder::<_, _, _>::new();
+/// let a = builder.node("a", 1);
+/// builder.edge("a", "b", "ab");
+/// builder.edge("b", "c", "bc");
+/// assert_eq!(builder.node("a", 2), a);
+///
+/// let graph = builder.build();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph[a], 2);
+/// ```
+pub struct GraphBuilder<K, N, E, Ty = Directed, Ix = DefaultIx> {
+    graph: Graph<N, E, Ty, Ix>,
+    nodes: HashMap<K, NodeIndex<Ix>>,
+}
+
+impl<K, N, E, Ty, Ix> fmt::Debug for GraphBuilder<K, N, E, Ty, Ix>
+where
+    K: fmt::Debug,
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GraphBuilder")
+            .field("graph", &self.graph)
+            .field("nodes", &self.nodes)
+            .finish()
+    }
+}
+
+impl<K, N, E, Ty, Ix> Default for GraphBuilder<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, N, E, Ty, Ix> GraphBuilder<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new, empty `GraphBuilder`.
+    pub fn new() -> Self {
+        GraphBuilder {
+            graph: Graph::default(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Return the node of `key`, adding it with `weight` if the key is new.
+    ///
+    /// If the key already has a node, its weight is replaced with `weight`, so the weights of
+    /// nodes first added by `edge` can be set afterwards.
+    ///
+    /// **Panics** if the Graph is at the maximum number of nodes for its index type.
+    pub fn node(&mut self, key: K, weight: N) -> NodeIndex<Ix> {
+        match self.nodes.entry(key) {
+            Entry::Occupied(entry) => {
+                let ix = *entry.get();
+                self.graph[ix] = weight;
+                ix
+            }
+            Entry::Vacant(entry) => *entry.insert(self.graph.add_node(weight)),
+        }
+    }
+
+    /// Return the node of `key`, if it has one.
+    pub fn node_index(&self, key: &K) -> Option<NodeIndex<Ix>> {
+        self.nodes.get(key).cloned()
+    }
+
+    /// Add an edge from the node of `from` to the node of `to`, and return its index.
+    ///
+    /// A key without a node is given one, with the default weight.
+    ///
+    /// **Panics** if the Graph is at the maximum number of nodes or edges for its index type.
+    pub fn edge(&mut self, from: K, to: K, weight: E) -> EdgeIndex<Ix>
+    where
+        N: Default,
+    {
+        let a = self.node_or_default(from);
+        let b = self.node_or_default(to);
+        self.graph.add_edge(a, b, weight)
+    }
+
+    fn node_or_default(&mut self, key: K) -> NodeIndex<Ix>
+    where
+        N: Default,
+    {
+        let graph = &mut self.graph;
+        *self
+            .nodes
+            .entry(key)
+            .or_insert_with(|| graph.add_node(N::default()))
+    }
+
+    /// Return the assembled graph.
+    ///
+    /// The nodes are numbered in the order their keys were first used.
+    pub fn build(self) -> Graph<N, E, Ty, Ix> {
+        self.graph
+    }
+}
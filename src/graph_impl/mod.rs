@@ -1,4 +1,5 @@
 use std::cmp;
+use std::collections::HashMap;
 use std::fmt;
 use std::hash::Hash;
 use std::iter;
@@ -54,6 +55,21 @@ unsafe impl IndexType for usize {
     }
 }
 
+unsafe impl IndexType for u64 {
+    #[inline(always)]
+    fn new(x: usize) -> Self {
+        x as u64
+    }
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+    #[inline(always)]
+    fn max() -> Self {
+        ::std::u64::MAX
+    }
+}
+
 unsafe impl IndexType for u32 {
     #[inline(always)]
     fn new(x: usize) -> Self {
@@ -626,6 +642,9 @@ where
 
     /// Access the source and target nodes for `e`.
     ///
+    /// The endpoints are in the order they were given to `add_edge`. In an undirected graph,
+    /// this order is kept too, but it has no meaning: the edge connects them both ways.
+    ///
     /// If edge `e` doesn't exist in the graph, return `None`.
     pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
         self.edges
@@ -690,6 +709,36 @@ where
         Some(node.weight)
     }
 
+    /// Remove `a` from the graph if it exists, and return its weight with the former index of
+    /// the node that took its place.
+    /// If it doesn't exist in the graph, return `None`.
+    ///
+    /// This is `.remove_node()`, telling which node was relocated: the last node of the
+    /// graph moves from its index to `a`, unless `a` was the last node, in which case no node
+    /// moves and the relocated index is `None`. No other node index changes. Edge indices are
+    /// invalidated as they would be following the removal of each edge with an endpoint in
+    /// `a`.
+    ///
+    /// Computes in **O(e')** time, like `.remove_node()`.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    ///
+    /// let mut g = Graph::<_, ()>::new();
+    /// let a = g.add_node("a");
+    /// let b = g.add_node("b");
+    /// let c = g.add_node("c");
+    /// assert_eq!(g.swap_remove_node(a), Some(("a", Some(c))));
+    /// // "c" is now at the index of "a"
+    /// assert_eq!(g[a], "c");
+    /// assert_eq!(g.swap_remove_node(b), Some(("b", None)));
+    /// ```
+    pub fn swap_remove_node(&mut self, a: NodeIndex<Ix>) -> Option<(N, Option<NodeIndex<Ix>>)> {
+        let last = NodeIndex::new(self.node_count().checked_sub(1)?);
+        let weight = self.remove_node(a)?;
+        Some((weight, if a == last { None } else { Some(last) }))
+    }
+
     /// For edge `e` with endpoints `edge_node`, replace links to it,
     /// with links to `edge_next`.
     fn change_edge_links(
@@ -766,6 +815,72 @@ where
         Some(edge.weight)
     }
 
+    /// Contract the edge `e`: remove it and merge its two endpoints into a single node,
+    /// with weight `merge(source_weight, target_weight)`.
+    ///
+    /// The source of `e` is kept as the merged node, and every other edge of the target is
+    /// moved to it. No edges are deduplicated: an edge that was parallel to `e` becomes a
+    /// self loop, and two edges to a common neighbor become parallel edges. Use
+    /// `.retain_edges()` to remove them.
+    ///
+    /// Return the index of the merged node, or `None` if `e` doesn't exist.
+    /// If `e` is a self loop, it is removed and `merge` is not called.
+    ///
+    /// The target is then removed; this invalidates node indices as `.remove_node()` does,
+    /// so the merged node has the index of the target if the source was the last node.
+    /// The moved edges keep their indices, and other edge indices are invalidated as by
+    /// `.remove_edge(e)`. If `merge` panics, the graph is left in an unspecified state.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges of the target.
+    ///
+    /// ```
+    /// use petgraph::graph::{edge_index, node_index, UnGraph};
+    ///
+    /// // a triangle: contracting one side leaves two parallel edges
+    /// let mut g = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    /// let merged = g.contract_edge(edge_index(0), |a, b| a + b).unwrap();
+    /// assert_eq!(merged, node_index(0));
+    /// assert_eq!(g.node_count(), 2);
+    /// assert_eq!(g.edge_count(), 2);
+    /// assert_eq!(g.edges_connecting(merged, node_index(1)).count(), 2);
+    /// ```
+    pub fn contract_edge<F>(&mut self, e: EdgeIndex<Ix>, merge: F) -> Option<NodeIndex<Ix>>
+    where
+        F: FnOnce(N, N) -> N,
+    {
+        let (a, b) = self.edge_endpoints(e)?;
+        self.remove_edge(e);
+        if a == b {
+            return Some(a);
+        }
+
+        // Move the edges of `b` to the front of the edge lists of `a`, in place.
+        for &d in &DIRECTIONS {
+            let k = d.index();
+            let mut next = self.nodes[b.index()].next[k];
+            self.nodes[b.index()].next[k] = EdgeIndex::end();
+            while next != EdgeIndex::end() {
+                let edge = &mut self.edges[next.index()];
+                let moved = next;
+                next = edge.next[k];
+                edge.node[k] = a;
+                edge.next[k] = self.nodes[a.index()].next[k];
+                self.nodes[a.index()].next[k] = moved;
+            }
+        }
+
+        let b_weight = self.remove_node(b).unwrap();
+        let merged = if a.index() == self.nodes.len() { b } else { a };
+        // Take the weight of the merged node by moving it to the end of the nodes.
+        let last = self.nodes.len() - 1;
+        self.nodes.swap(merged.index(), last);
+        let mut node = self.nodes.pop().unwrap();
+        node.weight = merge(node.weight, b_weight);
+        self.nodes.push(node);
+        self.nodes.swap(merged.index(), last);
+        Some(merged)
+    }
+
     /// Return an iterator of all nodes with an edge starting from `a`.
     ///
     /// - `Directed`: Outgoing edges from `a`.
@@ -871,12 +986,86 @@ where
         }
     }
 
+    /// Return the number of edges of `a`, in either direction.
+    ///
+    /// A self loop at `a` counts twice, once as an outgoing and once as an incoming edge,
+    /// and each of several parallel edges counts on its own.
+    /// This is `.out_degree(a) + .in_degree(a)`, for both `Directed` and `Undirected` graphs.
+    ///
+    /// Return 0 if the node doesn't exist.
+    ///
+    /// ```
+    /// use petgraph::graph::{node_index, UnGraph};
+    ///
+    /// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 1), (1, 1)]);
+    /// assert_eq!(g.degree(node_index(0)), 2);
+    /// assert_eq!(g.degree(node_index(1)), 4);
+    /// ```
+    pub fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.out_degree(a) + self.in_degree(a)
+    }
+
+    /// Return the number of edges with `a` as their source.
+    ///
+    /// A self loop at `a` counts once. In an `Undirected` graph, the source of an edge is
+    /// the first node given to `add_edge`; see `.degree(a)` for the number of edges.
+    ///
+    /// Return 0 if the node doesn't exist.
+    pub fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.count_edges_directed(a, Outgoing)
+    }
+
+    /// Return the number of edges with `a` as their target.
+    ///
+    /// A self loop at `a` counts once. In an `Undirected` graph, the target of an edge is
+    /// the second node given to `add_edge`; see `.degree(a)` for the number of edges.
+    ///
+    /// Return 0 if the node doesn't exist.
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.count_edges_directed(a, Incoming)
+    }
+
+    /// Walk the edge list of `a` in direction `dir`, without skipping self loops.
+    fn count_edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        let k = dir.index();
+        let mut next = match self.nodes.get(a.index()) {
+            None => EdgeIndex::end(),
+            Some(n) => n.next[k],
+        };
+        let mut count = 0;
+        while next != EdgeIndex::end() {
+            count += 1;
+            next = self.edges[next.index()].next[k];
+        }
+        count
+    }
+
     /// Return an iterator over all the edges connecting `a` and `b`.
     ///
-    /// - `Directed`: Outgoing edges from `a`.
-    /// - `Undirected`: All edges connected to `a`.
+    /// - `Directed`: Outgoing edges from `a` to `b`; the edges from `b` to `a`
+    ///   are `.edges_connecting(b, a)`.
+    /// - `Undirected`: All edges between `a` and `b`, in either direction.
+    ///
+    /// Parallel edges are all included, so this can be used to inspect or
+    /// remove a specific one in a multigraph.
     ///
     /// Iterator element type is `EdgeReference<E, Ix>`.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    /// use petgraph::visit::EdgeRef;
+    ///
+    /// let g = Graph::<(), char>::from_edges(&[(0, 1, 'a'), (1, 0, 'b'), (0, 1, 'c')]);
+    /// let weights: Vec<_> = g.edges_connecting(0.into(), 1.into()).map(|e| *e.weight()).collect();
+    /// assert_eq!(weights, vec!['c', 'a']);
+    /// assert_eq!(g.edges_connecting(1.into(), 0.into()).count(), 1);
+    ///
+    /// let ug = g.into_undirected();
+    /// assert_eq!(ug.edges_connecting(1.into(), 0.into()).count(), 3);
+    /// ```
     pub fn edges_connecting(
         &self,
         a: NodeIndex<Ix>,
@@ -889,6 +1078,55 @@ where
         }
     }
 
+    /// Return an iterator over the self loops: the edges from a node to itself.
+    ///
+    /// Computes in **O(|E|)** time.
+    pub fn self_loops(&self) -> impl Iterator<Item = EdgeIndex<Ix>> + '_ {
+        self.edge_indices()
+            .filter(move |&e| self.edges[e.index()].source() == self.edges[e.index()].target())
+    }
+
+    /// Return an iterator over the pairs of parallel edges: the edges with the
+    /// same endpoints, as in `.edges_connecting()`.
+    ///
+    /// - `Directed`: Edges with the same source and the same target.
+    /// - `Undirected`: Edges between the same two nodes, in either direction.
+    ///
+    /// Each pair is yielded once, with the lower edge index first. Three
+    /// parallel edges make three pairs.
+    ///
+    /// Computes in **O(|E| + e' + p)** time, where **e'** is the sum of the
+    /// degrees of the sources of the edges, and **p** the number of pairs.
+    ///
+    /// ```
+    /// use petgraph::graph::{edge_index, UnGraph};
+    ///
+    /// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 0), (2, 2)]);
+    /// assert!(!g.is_simple());
+    /// assert_eq!(g.self_loops().collect::<Vec<_>>(), vec![edge_index(3)]);
+    /// assert_eq!(
+    ///     g.parallel_edges().collect::<Vec<_>>(),
+    ///     vec![(edge_index(0), edge_index(2))]
+    /// );
+    /// ```
+    pub fn parallel_edges(&self) -> impl Iterator<Item = (EdgeIndex<Ix>, EdgeIndex<Ix>)> + '_ {
+        self.edge_indices().flat_map(move |e| {
+            let edge = &self.edges[e.index()];
+            self.edges_connecting(edge.source(), edge.target())
+                .map(|f| f.index)
+                .filter(move |&f| f > e)
+                .map(move |f| (e, f))
+        })
+    }
+
+    /// Return `true` if the graph is simple: if it has no self loops and no
+    /// parallel edges.
+    ///
+    /// In a `Directed` graph, two edges `a -> b` and `b -> a` are not parallel.
+    pub fn is_simple(&self) -> bool {
+        self.self_loops().next().is_none() && self.parallel_edges().next().is_none()
+    }
+
     /// Lookup if there is an edge from `a` to `b`.
     ///
     /// Computes in **O(e')** time, where **e'** is the number of edges
@@ -1165,6 +1403,27 @@ where
     }
 
     /// Reverse the direction of all edges
+    ///
+    /// Node and edge indices and weights are unchanged. The outgoing edges of
+    /// each node are its former incoming edges, in the same order, and vice versa.
+    ///
+    /// This works in place; to keep the original, reverse a clone of the graph
+    /// instead, or use the `Reversed` adaptor to view a graph with its edges
+    /// reversed without changing it.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    /// use petgraph::graph::node_index as n;
+    /// use petgraph::visit::Reversed;
+    /// use petgraph::algo::has_path_connecting;
+    ///
+    /// let gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    /// let mut rev = gr.clone();
+    /// rev.reverse();
+    /// assert!(has_path_connecting(&rev, n(2), n(0), None));
+    /// assert!(has_path_connecting(Reversed(&gr), n(2), n(0), None));
+    /// assert!(!has_path_connecting(&gr, n(2), n(0), None));
+    /// ```
     pub fn reverse(&mut self) {
         // swap edge endpoints,
         // edge incoming / outgoing lists,
@@ -1258,6 +1517,10 @@ where
     /// the graph can be walked and associated data modified.
     ///
     /// The order nodes are visited is not specified.
+    ///
+    /// Each removal invalidates indices like `.remove_node()`: the last node
+    /// takes the index of the removed node, so the indices of the kept nodes
+    /// may change, and so may edge indices. Use `StableGraph` to keep them.
     pub fn retain_nodes<F>(&mut self, mut visit: F)
     where
         F: FnMut(Frozen<Self>, NodeIndex<Ix>) -> bool,
@@ -1278,6 +1541,10 @@ where
     /// the graph can be walked and associated data modified.
     ///
     /// The order edges are visited is not specified.
+    ///
+    /// Each removal invalidates indices like `.remove_edge()`: the last edge
+    /// takes the index of the removed edge, so the indices of the kept edges
+    /// may change. Node indices are unchanged.
     pub fn retain_edges<F>(&mut self, mut visit: F)
     where
         F: FnMut(Frozen<Self>, EdgeIndex<Ix>) -> bool,
@@ -1297,7 +1564,9 @@ where
     /// Edge weights `E` may either be specified in the list,
     /// or they are filled with default values.
     ///
-    /// Nodes are inserted automatically to match the edges.
+    /// Nodes are inserted automatically to match the edges: the graph has one node
+    /// more than the highest node index referenced, and the nodes in between that
+    /// no edge uses are inserted too.
     ///
     /// ```
     /// use petgraph::Graph;
@@ -1307,6 +1576,12 @@ where
     ///     (1, 2), (1, 3),
     ///     (2, 3),
     /// ]);
+    /// assert_eq!(gr.node_count(), 4);
+    /// assert_eq!(gr.edge_count(), 6);
+    ///
+    /// let gr = Graph::<(), i32>::from_edges(&[(1, 3, 10), (3, 1, 20)]);
+    /// assert_eq!(gr.node_count(), 4);
+    /// assert_eq!(gr.edge_weights().sum::<i32>(), 30);
     /// ```
     pub fn from_edges<I>(iterable: I) -> Self
     where
@@ -1326,7 +1601,8 @@ where
     /// Edge weights `E` may either be specified in the list,
     /// or they are filled with default values.
     ///
-    /// Nodes are inserted automatically to match the edges.
+    /// Nodes are inserted automatically to match the edges: if an edge refers to a
+    /// node index that is not in the graph yet, nodes are added up to that index.
     pub fn extend_with_edges<I>(&mut self, iterable: I)
     where
         I: IntoIterator,
@@ -1418,6 +1694,65 @@ where
         g
     }
 
+    /// Create a new `Graph` of the nodes in `nodes`, and the edges between them.
+    ///
+    /// Return the graph, and a map from the index of each node in `self` to its index in the
+    /// new graph. The nodes are numbered in the order of `nodes`, ignoring any duplicates, and
+    /// the edges keep the order they have in `self`.
+    ///
+    /// Computes in **O(|V'| + |E'| log |E'|)** time, where **|V'|** is the number of nodes given
+    /// and **|E'|** the number of their outgoing edges.
+    ///
+    /// **Panics** if a node index is out of bounds.
+    ///
+    /// ```
+    /// use petgraph::graph::{node_index as n, UnGraph};
+    ///
+    /// let g = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+    /// let (triangle, map) = g.induced_subgraph(&[n(2), n(1), n(0)]);
+    /// assert_eq!(triangle.node_count(), 3);
+    /// assert_eq!(triangle.edge_count(), 3);
+    /// assert_eq!(map[&n(2)], n(0));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn induced_subgraph(
+        &self,
+        nodes: &[NodeIndex<Ix>],
+    ) -> (Graph<N, E, Ty, Ix>, HashMap<NodeIndex<Ix>, NodeIndex<Ix>>)
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let mut g = Graph::with_capacity(nodes.len(), 0);
+        let mut node_map = HashMap::with_capacity(nodes.len());
+        for &a in nodes {
+            let weight = &self.nodes[a.index()].weight;
+            node_map
+                .entry(a)
+                .or_insert_with(|| g.add_node(weight.clone()));
+        }
+        let mut edges = Vec::new();
+        for &a in node_map.keys() {
+            let mut edix = self.nodes[a.index()].next[0];
+            while let Some(edge) = self.edges.get(edix.index()) {
+                if node_map.contains_key(&edge.target()) {
+                    edges.push(edix);
+                }
+                edix = edge.next[0];
+            }
+        }
+        edges.sort();
+        for e in edges {
+            let edge = &self.edges[e.index()];
+            g.add_edge(
+                node_map[&edge.source()],
+                node_map[&edge.target()],
+                edge.weight.clone(),
+            );
+        }
+        (g, node_map)
+    }
+
     /// Convert the graph into either undirected or directed. No edge adjustments
     /// are done, so you may want to go over the result to remove or add edges.
     ///
@@ -1433,6 +1768,187 @@ where
         }
     }
 
+    /// Convert the graph into an undirected graph, where each edge connects
+    /// its endpoints in both directions.
+    ///
+    /// Each edge stays a separate edge: a pair of antiparallel edges `a -> b`
+    /// and `b -> a` become two parallel edges between `a` and `b`. Node and
+    /// edge indices and weights are preserved.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    ///
+    /// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 0), (1, 2)]);
+    /// let ug = g.into_undirected();
+    /// assert_eq!(ug.edge_count(), 3);
+    /// assert!(ug.find_edge(2.into(), 1.into()).is_some());
+    /// ```
+    pub fn into_undirected(self) -> Graph<N, E, Undirected, Ix> {
+        self.into_edge_type()
+    }
+
+    /// Convert the graph into a directed graph, where each edge goes from
+    /// its source to its target.
+    ///
+    /// Each edge becomes a single directed edge, in the order its endpoints
+    /// were given to [`.add_edge()`](#method.add_edge); add the reversed edges
+    /// to keep a path through every edge in both directions. Node and edge
+    /// indices and weights are preserved.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// ```
+    /// use petgraph::graph::UnGraph;
+    ///
+    /// let ug = UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 1)]);
+    /// let g = ug.into_directed();
+    /// assert!(g.find_edge(2.into(), 1.into()).is_some());
+    /// assert!(g.find_edge(1.into(), 2.into()).is_none());
+    /// ```
+    pub fn into_directed(self) -> Graph<N, E, Directed, Ix> {
+        self.into_edge_type()
+    }
+
+    /// Return the adjacency matrix of the graph: an `n × n` matrix, where `n`
+    /// is the number of nodes, with `matrix[i][j]` true if there is an edge
+    /// from the node with index `i` to the node with index `j`.
+    ///
+    /// In an `Undirected` graph, the matrix is symmetric. Parallel edges
+    /// collapse into a single entry.
+    ///
+    /// Computes in **O(|V|² + |E|)** time.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    ///
+    /// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 2)]);
+    /// assert_eq!(
+    ///     g.to_adjacency_matrix(),
+    ///     vec![
+    ///         vec![false, true, false],
+    ///         vec![false, false, true],
+    ///         vec![false, false, false],
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_adjacency_matrix(&self) -> Vec<Vec<bool>> {
+        self.to_weight_matrix(|_| ())
+            .into_iter()
+            .map(|row| row.into_iter().map(|w| w.is_some()).collect())
+            .collect()
+    }
+
+    /// Return the weight matrix of the graph: an `n × n` matrix, where `n` is
+    /// the number of nodes, with `matrix[i][j]` equal to `Some(edge_weight(w))`
+    /// if there is an edge with weight `w` from the node with index `i` to the
+    /// node with index `j`, and `None` otherwise.
+    ///
+    /// In an `Undirected` graph, the matrix is symmetric. Of parallel edges,
+    /// the entry is from the edge with the highest index.
+    ///
+    /// Computes in **O(|V|² + |E|)** time.
+    ///
+    /// ```
+    /// use petgraph::graph::UnGraph;
+    ///
+    /// let g = UnGraph::<(), u32>::from_edges(&[(0, 1, 7), (1, 1, 2)]);
+    /// assert_eq!(
+    ///     g.to_weight_matrix(|&w| w * 10),
+    ///     vec![vec![None, Some(70)], vec![Some(70), Some(20)]]
+    /// );
+    /// ```
+    pub fn to_weight_matrix<F, K>(&self, mut edge_weight: F) -> Vec<Vec<Option<K>>>
+    where
+        F: FnMut(&E) -> K,
+        K: Clone,
+    {
+        let n = self.node_count();
+        let mut matrix = vec![vec![None; n]; n];
+        for edge in &self.edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            let w = edge_weight(&edge.weight);
+            if !self.is_directed() {
+                matrix[b][a] = Some(w.clone());
+            }
+            matrix[a][b] = Some(w);
+        }
+        matrix
+    }
+
+    /// Return `true` if the graphs are identical: the same node weights at
+    /// the same node indices, and the same edges, with the same endpoints and
+    /// weights.
+    ///
+    /// The edges are compared as a multiset, so the order of the edges and
+    /// their indices do not matter, but the number of parallel edges does. In
+    /// an `Undirected` graph, edges `a - b` and `b - a` are the same.
+    ///
+    /// This is identity, not isomorphism: see `algo::is_isomorphic_matching`
+    /// to compare graphs up to a renumbering of their nodes.
+    ///
+    /// Computes in **O(|V| + |E| log |E| + p²)** time, where **p** is the
+    /// largest number of parallel edges.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    ///
+    /// let g = Graph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 2)]);
+    /// let h = Graph::<(), u32>::from_edges(&[(1, 2, 2), (0, 1, 1)]);
+    /// assert!(g.is_equal(&h));
+    /// assert!(!g.is_equal(&Graph::from_edges(&[(0, 1, 1), (1, 2, 3)])));
+    /// ```
+    pub fn is_equal(&self, other: &Self) -> bool
+    where
+        N: PartialEq,
+        E: PartialEq,
+    {
+        if self.node_count() != other.node_count()
+            || self.edge_count() != other.edge_count()
+            || self.node_weights().ne(other.node_weights())
+        {
+            return false;
+        }
+        // Sort both edge lists by endpoints, then match the weights of each
+        // group of parallel edges
+        let endpoints = |g: &Self, e: &usize| {
+            let (a, b) = (g.edges[*e].source(), g.edges[*e].target());
+            if g.is_directed() {
+                (a, b)
+            } else {
+                (cmp::min(a, b), cmp::max(a, b))
+            }
+        };
+        let mut ours: Vec<usize> = (0..self.edge_count()).collect();
+        let mut theirs = ours.clone();
+        ours.sort_by_key(|e| endpoints(self, e));
+        theirs.sort_by_key(|e| endpoints(other, e));
+        let mut i = 0;
+        while i < ours.len() {
+            let key = endpoints(self, &ours[i]);
+            let mut end = i;
+            while end < ours.len() && endpoints(self, &ours[end]) == key {
+                end += 1;
+            }
+            let mut group = theirs[i..end].to_vec();
+            if group.iter().any(|e| endpoints(other, e) != key) {
+                return false;
+            }
+            for e in &ours[i..end] {
+                let weight = &self.edges[*e].weight;
+                match group.iter().position(|f| other.edges[*f].weight == *weight) {
+                    Some(pos) => {
+                        group.swap_remove(pos);
+                    }
+                    None => return false,
+                }
+            }
+            i = end;
+        }
+        true
+    }
+
     //
     // internal methods
     //
@@ -2393,10 +2909,13 @@ where
     }
 }
 
+mod builder;
 mod frozen;
 #[cfg(feature = "stable_graph")]
 pub mod stable_graph;
 
+pub use self::builder::GraphBuilder;
+
 /// `Frozen` is a graph wrapper.
 ///
 /// The `Frozen` only allows shared access (read-only) to the
@@ -2408,4 +2927,30 @@ pub mod stable_graph;
 ///
 /// See indexing implementations and the traits `Data` and `DataMap`
 /// for read-write access to the graph's weights.
+///
+/// ```
+/// use petgraph::data::DataMapMut;
+/// use petgraph::graph::{node_index, Frozen, Graph};
+///
+/// let mut gr = Graph::<u32, ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let mut frozen = Frozen::new(&mut gr);
+///
+/// // stamp the degree onto each node
+/// for n in frozen.node_indices() {
+///     let degree = frozen.neighbors_undirected(n).count() as u32;
+///     *frozen.node_weight_mut(n).unwrap() = degree;
+/// }
+/// frozen[node_index(0)] += 10;
+/// assert_eq!(gr.node_weights().collect::<Vec<_>>(), vec![&11, &2, &1]);
+/// ```
+///
+/// The structure of the graph cannot change:
+///
+/// ```compile_fail
+/// use petgraph::graph::{Frozen, Graph};
+///
+/// let mut gr = Graph::<u32, ()>::new();
+/// let mut frozen = Frozen::new(&mut gr);
+/// frozen.add_node(0);
+/// ```
 pub struct Frozen<'a, G: 'a>(&'a mut G);
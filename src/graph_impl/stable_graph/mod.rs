@@ -513,7 +513,9 @@ where
             .filter_map(|maybe_node| maybe_node.as_mut())
     }
 
-    /// Return an iterator over the node indices of the graph
+    /// Return an iterator over the node indices of the graph, in increasing order.
+    ///
+    /// The indices of removed nodes are skipped.
     pub fn node_indices(&self) -> NodeIndices<N, Ix> {
         NodeIndices {
             iter: enumerate(self.raw_nodes()),
@@ -567,7 +569,9 @@ where
         }
     }
 
-    /// Return an iterator over the edge indices of the graph
+    /// Return an iterator over the edge indices of the graph, in increasing order.
+    ///
+    /// The indices of removed edges are skipped.
     pub fn edge_indices(&self) -> EdgeIndices<E, Ix> {
         EdgeIndices {
             iter: enumerate(self.raw_edges()),
@@ -576,10 +580,17 @@ where
 
     /// Return an iterator over all the edges connecting `a` and `b`.
     ///
-    /// - `Directed`: Outgoing edges from `a`.
-    /// - `Undirected`: All edges connected to `a`.
+    /// - `Directed`: Outgoing edges from `a` to `b`; the edges from `b` to `a`
+    ///   are `.edges_connecting(b, a)`.
+    /// - `Undirected`: All edges between `a` and `b`, in either direction.
+    ///
+    /// Parallel edges are all included, so this can be used to inspect or
+    /// remove a specific one in a multigraph.
     ///
     /// Iterator element type is `EdgeReference<E, Ix>`.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
     pub fn edges_connecting(
         &self,
         a: NodeIndex<Ix>,
@@ -0,0 +1,263 @@
+//! Simple GraphML file format output.
+
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+static EDGE_DEFAULT: [&str; 2] = ["undirected", "directed"];
+static INDENT: &str = "  ";
+
+/// Write a graph in [GraphML](http://graphml.graphdrawing.org/) format, as read by tools like
+/// Gephi and yEd.
+///
+/// Each node has the id `n` followed by its index, and a `label` attribute with its weight.
+/// Each edge has the id `e` followed by its position in the edge references of the graph, and
+/// a `label` attribute with its weight. The labels are formatted with `Display`, and escaped
+/// so that the document stays well-formed XML: the characters `& < > " '` are replaced with
+/// entities, and control characters that XML does not allow are replaced with `U+FFFD`.
+///
+/// The `edgedefault` of the graph is `directed` or `undirected`, depending on the graph.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::graphml::to_graphml;
+///
+/// let mut graph = Graph::<_, _>::new();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B & C");
+/// graph.add_edge(a, b, 1.5);
+///
+/// let mut out = Vec::new();
+/// to_graphml(&graph, &mut out).unwrap();
+/// let xml = String::from_utf8(out).unwrap();
+///
+/// assert!(xml.contains(r#"<graph id="G" edgedefault="directed">"#));
+/// assert!(xml.contains(r#"<data key="node_label">B &amp; C</data>"#));
+/// assert!(xml.contains(r#"<edge id="e0" source="n0" target="n1">"#));
+/// ```
+pub fn to_graphml<G, W>(g: G, out: &mut W) -> io::Result<()>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeWeight: Display,
+    G::EdgeWeight: Display,
+    W: Write,
+{
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    writeln!(
+        out,
+        r#"{}<key id="node_label" for="node" attr.name="label" attr.type="string"/>"#,
+        INDENT
+    )?;
+    writeln!(
+        out,
+        r#"{}<key id="edge_label" for="edge" attr.name="label" attr.type="string"/>"#,
+        INDENT
+    )?;
+    writeln!(
+        out,
+        r#"{}<graph id="G" edgedefault="{}">"#,
+        INDENT,
+        EDGE_DEFAULT[g.is_directed() as usize]
+    )?;
+    for node in g.node_references() {
+        writeln!(
+            out,
+            r#"{0}{0}<node id="n{1}"><data key="node_label">{2}</data></node>"#,
+            INDENT,
+            g.to_index(node.id()),
+            Escaped(node.weight()),
+        )?;
+    }
+    for (i, edge) in g.edge_references().enumerate() {
+        writeln!(
+            out,
+            r#"{0}{0}<edge id="e{1}" source="n{2}" target="n{3}"><data key="edge_label">{4}</data></edge>"#,
+            INDENT,
+            i,
+            g.to_index(edge.source()),
+            g.to_index(edge.target()),
+            Escaped(edge.weight()),
+        )?;
+    }
+    writeln!(out, "{}</graph>", INDENT)?;
+    writeln!(out, "</graphml>")
+}
+
+/// Escape for XML text and attribute values
+struct Escaper<W>(W);
+
+impl<W> fmt::Write for Escaper<W>
+where
+    W: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '&' => self.0.write_str("&amp;"),
+            '<' => self.0.write_str("&lt;"),
+            '>' => self.0.write_str("&gt;"),
+            '"' => self.0.write_str("&quot;"),
+            '\'' => self.0.write_str("&apos;"),
+            '\t' | '\n' | '\r' => self.0.write_char(c),
+            // not allowed in XML 1.0, even as character references
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => self.0.write_char('\u{fffd}'),
+            _ => self.0.write_char(c),
+        }
+    }
+}
+
+/// Pass Display formatting through the XML escaping filter
+struct Escaped<T>(T);
+
+impl<T> fmt::Display for Escaped<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use std::fmt::Write;
+        write!(&mut Escaper(f), "{}", &self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{to_graphml, Escaper};
+    use crate::prelude::Graph;
+    use std::fmt::Write;
+
+    fn graphml<G>(g: G) -> String
+    where
+        G: crate::visit::IntoNodeReferences
+            + crate::visit::IntoEdgeReferences
+            + crate::visit::NodeIndexable
+            + crate::visit::GraphProp,
+        G::NodeWeight: std::fmt::Display,
+        G::EdgeWeight: std::fmt::Display,
+    {
+        let mut out = Vec::new();
+        to_graphml(g, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Check that `xml` is well-formed, and count the elements with each name
+    fn parse(xml: &str) -> Vec<(String, usize)> {
+        let body = xml
+            .strip_prefix(r#"<?xml version="1.0" encoding="UTF-8"?>"#)
+            .expect("xml declaration");
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        let mut open = Vec::new();
+        let mut rest = body;
+        while let Some(start) = rest.find(|c| c == '<' || c == '&') {
+            if rest[start..].starts_with('&') {
+                let end = rest[start..].find(';').expect("unterminated entity");
+                let entity = &rest[start..start + end + 1];
+                assert!(
+                    ["&amp;", "&lt;", "&gt;", "&quot;", "&apos;"].contains(&entity),
+                    "unknown entity {}",
+                    entity
+                );
+                rest = &rest[start + end + 1..];
+                continue;
+            }
+            let end = rest[start..].find('>').expect("unterminated tag") + start;
+            let tag = &rest[start + 1..end];
+            assert!(!tag.contains('<'), "'<' in tag {}", tag);
+            // attribute values are quoted, and contain no raw '<' or unescaped quotes
+            assert_eq!(
+                tag.matches('"').count() % 2,
+                0,
+                "unbalanced quotes in {}",
+                tag
+            );
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(name), "mismatched closing tag");
+            } else {
+                let name = tag.split_whitespace().next().unwrap().trim_end_matches('/');
+                match counts.iter_mut().find(|(n, _)| n == name) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((name.to_string(), 1)),
+                }
+                if !tag.ends_with('/') {
+                    open.push(name);
+                }
+            }
+            rest = &rest[end + 1..];
+        }
+        assert!(open.is_empty(), "unclosed tags {:?}", open);
+        assert!(!rest.contains('>'));
+        counts
+    }
+
+    fn count(counts: &[(String, usize)], name: &str) -> usize {
+        counts
+            .iter()
+            .find(|(n, _)| n == name)
+            .map_or(0, |&(_, c)| c)
+    }
+
+    #[test]
+    fn test_escape() {
+        let mut buff = String::new();
+        {
+            let mut e = Escaper(&mut buff);
+            let _ = e.write_str("<a href=\"x\">&'\u{1}\n");
+        }
+        assert_eq!(buff, "&lt;a href=&quot;x&quot;&gt;&amp;&apos;\u{fffd}\n");
+    }
+
+    #[test]
+    fn test_directed() {
+        let mut graph = Graph::<&str, &str>::new();
+        let a = graph.add_node("<node>");
+        let b = graph.add_node("\"quoted\" & 'single'");
+        graph.add_edge(a, b, "a -> b");
+        graph.add_edge(b, b, "</graphml>");
+        let xml = graphml(&graph);
+        let counts = parse(&xml);
+        assert_eq!(count(&counts, "graphml"), 1);
+        assert_eq!(count(&counts, "key"), 2);
+        assert_eq!(count(&counts, "node"), graph.node_count());
+        assert_eq!(count(&counts, "edge"), graph.edge_count());
+        assert_eq!(count(&counts, "data"), 4);
+        assert!(xml.contains(r#"edgedefault="directed""#));
+        assert!(xml.contains(r#"<edge id="e1" source="n1" target="n1">"#));
+        assert!(xml.contains("<data key=\"edge_label\">a -&gt; b</data>"));
+    }
+
+    #[test]
+    #[cfg(feature = "stable_graph")]
+    fn test_undirected_holes() {
+        use crate::prelude::{StableGraph, Undirected};
+
+        let mut graph = StableGraph::<u32, f64, Undirected>::with_capacity(0, 0);
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        let c = graph.add_node(2);
+        graph.add_edge(a, b, 0.5);
+        graph.add_edge(b, c, 1.5);
+        graph.remove_node(b);
+        graph.add_edge(a, c, 2.5);
+        let xml = graphml(&graph);
+        let counts = parse(&xml);
+        assert_eq!(count(&counts, "node"), 2);
+        assert_eq!(count(&counts, "edge"), 1);
+        assert!(xml.contains(r#"edgedefault="undirected""#));
+        assert!(xml.contains(r#"<node id="n2"><data key="node_label">2</data></node>"#));
+        assert!(xml.contains(r#"<edge id="e0" source="n0" target="n2">"#));
+    }
+}
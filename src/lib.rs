@@ -93,7 +93,7 @@
 //! # Crate features
 //!
 //! * **serde-1** -
-//!   Defaults off. Enables serialization for ``Graph, StableGraph, GraphMap`` using
+//!   Defaults off. Enables serialization for ``Graph, StableGraph, GraphMap, UnionFind`` using
 //!   [`serde 1.0`](https://crates.io/crates/serde). May require a more recent version
 //!   of Rust than petgraph alone.
 //! * **graphmap** -
@@ -136,12 +136,15 @@ pub mod data;
 pub mod adj;
 pub mod algo;
 pub mod csr;
+pub mod dimacs;
 pub mod dot;
+pub mod edge_list;
 #[cfg(feature = "generate")]
 pub mod generate;
 mod graph_impl;
 #[cfg(feature = "graphmap")]
 pub mod graphmap;
+pub mod graphml;
 mod iter_format;
 mod iter_utils;
 #[cfg(feature = "matrix_graph")]
@@ -162,8 +165,8 @@ pub mod graph {
     pub use crate::graph_impl::{
         edge_index, node_index, DefaultIx, DiGraph, Edge, EdgeIndex, EdgeIndices, EdgeReference,
         EdgeReferences, EdgeWeightsMut, Edges, EdgesConnecting, Externals, Frozen, Graph,
-        GraphIndex, IndexType, Neighbors, Node, NodeIndex, NodeIndices, NodeReferences,
-        NodeWeightsMut, UnGraph, WalkNeighbors,
+        GraphBuilder, GraphIndex, IndexType, Neighbors, Node, NodeIndex, NodeIndices,
+        NodeReferences, NodeWeightsMut, UnGraph, WalkNeighbors,
     };
 }
 
@@ -1,14 +1,18 @@
 //! Operators for creating new graphs from existings ones.
-use super::graph::{Graph, IndexType};
-use super::EdgeType;
-use crate::visit::IntoNodeReferences;
+use super::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
+use super::{Direction, EdgeType};
+use crate::visit::{EdgeRef, IntoNodeReferences};
 
 /// \[Generic\] complement of the graph
 ///
 /// Computes the graph complement of the input Graph and stores it
 /// in the provided empty output Graph.
 ///
-/// The function does not create self-loops.
+/// The function does not create self-loops. For a directed graph, the
+/// complement is over ordered pairs of nodes; for an undirected graph, over
+/// unordered pairs, so each missing edge is added once.
+///
+/// The input is assumed to be simple: parallel edges count as a single edge.
 ///
 /// Computes in **O(|V|^2*log(|V|))** time (average).
 ///
@@ -75,9 +79,170 @@ pub fn complement<N, E, Ty, Ix>(
     }
     for x in input.node_indices() {
         for y in input.node_indices() {
-            if x != y && !input.contains_edge(x, y) {
+            // an undirected edge is visited as both (x, y) and (y, x)
+            let first_visit = input.is_directed() || x < y;
+            if x != y && first_visit && !input.contains_edge(x, y) {
                 output.add_edge(x, y, weight.clone());
             }
         }
     }
 }
+
+/// \[Generic\] union of two graphs over the same node indices
+///
+/// The nodes of the union are the node indices of either graph, and its edges
+/// are the edges of either graph. Two edges are the same if they connect the
+/// same nodes, as in `contains_edge`: with the same source and target in a
+/// directed graph, in either direction in an undirected graph.
+///
+/// On overlap, the weights of `a` are preferred: the nodes of `a` keep their
+/// weights, followed by the extra nodes of `b`, and all edges of `a` are kept,
+/// including parallel edges, followed by the edges of `b` that are not in `a`.
+///
+/// Computes in **O(|V| + |E|·e')** time, where **e'** is the largest number
+/// of edges connected to a node of `a`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::operator::union;
+///
+/// let a = Graph::<(), char>::from_edges(&[(0, 1, 'a'), (1, 2, 'a')]);
+/// let b = Graph::<(), char>::from_edges(&[(1, 2, 'b'), (2, 3, 'b')]);
+/// let u = union(&a, &b);
+/// assert_eq!(u.node_count(), 4);
+/// assert_eq!(u.edge_count(), 3);
+/// assert_eq!(u[u.find_edge(1.into(), 2.into()).unwrap()], 'a');
+/// assert_eq!(u[u.find_edge(2.into(), 3.into()).unwrap()], 'b');
+/// ```
+pub fn union<N, E, Ty, Ix>(a: &Graph<N, E, Ty, Ix>, b: &Graph<N, E, Ty, Ix>) -> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    E: Clone,
+    N: Clone,
+{
+    let mut output = a.clone();
+    for (_node, weight) in b.node_references().skip(a.node_count()) {
+        output.add_node(weight.clone());
+    }
+    for edge in b.raw_edges() {
+        let (x, y) = (edge.source(), edge.target());
+        if !a.contains_edge(x, y) {
+            output.add_edge(x, y, edge.weight.clone());
+        }
+    }
+    output
+}
+
+/// \[Generic\] intersection of two graphs over the same node indices
+///
+/// The nodes of the intersection are the node indices of both graphs, and its
+/// edges are the edges of both graphs. Two edges are the same if they connect
+/// the same nodes, as in `contains_edge`: with the same source and target in a
+/// directed graph, in either direction in an undirected graph.
+///
+/// The weights are those of `a`: its nodes with an index in `b`, and its edges,
+/// including parallel edges, that are in `b`.
+///
+/// Computes in **O(|V| + |E|·e')** time, where **e'** is the largest number
+/// of edges connected to a node of `b`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::operator::intersection;
+///
+/// let a = Graph::<(), char>::from_edges(&[(0, 1, 'a'), (1, 2, 'a')]);
+/// let b = Graph::<(), char>::from_edges(&[(1, 2, 'b'), (2, 3, 'b')]);
+/// let i = intersection(&a, &b);
+/// assert_eq!(i.node_count(), 3);
+/// assert_eq!(i.edge_count(), 1);
+/// assert_eq!(i[i.find_edge(1.into(), 2.into()).unwrap()], 'a');
+/// ```
+pub fn intersection<N, E, Ty, Ix>(
+    a: &Graph<N, E, Ty, Ix>,
+    b: &Graph<N, E, Ty, Ix>,
+) -> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    E: Clone,
+    N: Clone,
+{
+    let node_count = a.node_count().min(b.node_count());
+    let mut output = Graph::with_capacity(node_count, 0);
+    for (_node, weight) in a.node_references().take(node_count) {
+        output.add_node(weight.clone());
+    }
+    for edge in a.raw_edges() {
+        let (x, y) = (edge.source(), edge.target());
+        if b.contains_edge(x, y) {
+            output.add_edge(x, y, edge.weight.clone());
+        }
+    }
+    output
+}
+
+/// \[Generic\] line graph of the graph
+///
+/// The line graph has a node for each edge of the input graph, with the edge
+/// index as its weight: node `i` is for edge `i`. Two of its nodes are adjacent
+/// if their edges are:
+///
+/// - `Directed`: consecutive, with the target of the first edge the source of
+///   the second. A self loop is consecutive to itself.
+/// - `Undirected`: distinct, and share an endpoint. Parallel edges are adjacent
+///   once.
+///
+/// Computes in **O(|V| + |E| + Σ d(v)²)** time, where **d(v)** is the degree of
+/// node **v**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{edge_index, UnGraph};
+/// use petgraph::operator::line_graph;
+///
+/// // a path with three edges
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let l = line_graph(&g);
+/// assert_eq!(l.node_count(), 3);
+/// assert_eq!(l[l.node_indices().last().unwrap()], edge_index(2));
+/// assert!(l.contains_edge(0.into(), 1.into()));
+/// assert!(l.contains_edge(1.into(), 2.into()));
+/// assert!(!l.contains_edge(0.into(), 2.into()));
+/// ```
+pub fn line_graph<N, E, Ty, Ix>(g: &Graph<N, E, Ty, Ix>) -> Graph<EdgeIndex<Ix>, (), Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut output = Graph::with_capacity(g.edge_count(), 0);
+    for e in g.edge_indices() {
+        output.add_node(e);
+    }
+    let node = |e: EdgeIndex<Ix>| NodeIndex::new(e.index());
+    if g.is_directed() {
+        for first in g.edge_references() {
+            for second in g.edges_directed(first.target(), Direction::Outgoing) {
+                output.add_edge(node(first.id()), node(second.id()), ());
+            }
+        }
+        return output;
+    }
+    let mut incident = Vec::new();
+    for v in g.node_indices() {
+        incident.clear();
+        incident.extend(g.edges(v).map(|e| (e.id(), e.target())));
+        for (i, &(first, x)) in incident.iter().enumerate() {
+            for &(second, y) in &incident[i + 1..] {
+                // parallel edges also share their other endpoint, add them from the lower one
+                if x == y && x < v {
+                    continue;
+                }
+                output.add_edge(node(first), node(second), ());
+            }
+        }
+    }
+    output
+}
@@ -1,7 +1,11 @@
 //! `UnionFind<K>` is a disjoint-set data structure.
 
 use super::graph::IndexType;
-use std::cmp::Ordering;
+use std::fmt;
+use std::mem;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// `UnionFind<K>` is a disjoint-set data structure. It tracks set membership of *n* elements
 /// indexed from *0* to *n - 1*. The scalar type is `K` which must be an unsigned integer type.
@@ -12,7 +16,12 @@ use std::cmp::Ordering;
 ///
 /// “The amortized time per operation is **O(α(n))** where **α(n)** is the
 /// inverse of **f(x) = A(x, x)** with **A** being the extremely fast-growing Ackermann function.”
-#[derive(Debug, Clone)]
+///
+/// The representatives are a function of the sequence of unions alone: which root goes below
+/// the other only depends on the ranks (or sizes) of the roots, and the path compression of
+/// lookups like `find_mut` never changes a root, or its rank. The same unions thus give the
+/// same representatives, whatever lookups are done in between.
+#[derive(Clone)]
 pub struct UnionFind<K> {
     // For element at index *i*, store the index of its parent; the representative itself
     // stores its own index. This forms equivalence classes which are the disjoint sets, each
@@ -21,9 +30,25 @@ pub struct UnionFind<K> {
     // It is a balancing tree structure,
     // so the ranks are logarithmic in the size of the container -- a byte is more than enough.
     //
+    // A representative of rank *r* has at least *2^r* elements in its set: the rank only grows
+    // when two sets of equal rank *r*, of at least *2^r* elements each, are unified. The rank is
+    // thus at most *log2(n)*, which is less than 64 for any *n* that fits in a `usize`.
+    //
     // Rank is separated out both to save space and to save cache in when searching in the parent
     // vector.
     rank: Vec<u8>,
+    // The number of elements in the set of each representative. Only the entries of
+    // representatives are meaningful; path compression never touches them.
+    size: Vec<usize>,
+    // Balance by set size instead of by rank when unifying.
+    by_size: bool,
+}
+
+/// Return the base 2 logarithm of `x`, rounded down.
+#[inline]
+fn log2(x: usize) -> u32 {
+    debug_assert!(x > 0);
+    (8 * mem::size_of::<usize>() as u32) - 1 - x.leading_zeros()
 }
 
 #[inline]
@@ -45,9 +70,147 @@ where
     /// Create a new `UnionFind` of `n` disjoint sets.
     pub fn new(n: usize) -> Self {
         let rank = vec![0; n];
+        let size = vec![1; n];
         let parent = (0..n).map(K::new).collect::<Vec<K>>();
 
-        UnionFind { parent, rank }
+        UnionFind {
+            parent,
+            rank,
+            size,
+            by_size: false,
+        }
+    }
+
+    /// Create a new `UnionFind` of `n` elements and unify the two sets containing
+    /// the elements of each pair in `edges`.
+    ///
+    /// **Panics** if any element of `edges` is out of bounds.
+    pub fn from_edges<I>(n: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (K, K)>,
+    {
+        let mut u = UnionFind::new(n);
+        for (x, y) in edges {
+            u.union(x, y);
+        }
+        u
+    }
+
+    /// Create a new `UnionFind` of `n` disjoint sets and union each pair of `edges`
+    /// in parallel.
+    ///
+    /// The edges are split into one chunk per thread of the current rayon pool, and each
+    /// chunk is collected into a labeling of its own. The labelings are then merged
+    /// sequentially, so peak memory use is *n* elements per chunk. The resulting partition
+    /// is the same as that of `from_edges`.
+    ///
+    /// **Panics** if any element of `edges` is out of bounds.
+    #[cfg(feature = "rayon")]
+    pub fn par_from_edges(n: usize, edges: &[(K, K)]) -> Self
+    where
+        K: Send + Sync,
+    {
+        let threads = rayon::current_num_threads();
+        let chunk_size = (edges.len() + threads - 1) / threads;
+        let labelings: Vec<Vec<K>> = edges
+            .par_chunks(chunk_size.max(1))
+            .map(|chunk| UnionFind::from_edges(n, chunk.iter().cloned()).into_labeling())
+            .collect();
+
+        let mut u = UnionFind::new(n);
+        for labeling in labelings {
+            for (i, &rep) in labeling.iter().enumerate() {
+                u.union(K::new(i), rep);
+            }
+        }
+        u
+    }
+
+    /// Create a new, empty `UnionFind` with space for at least `capacity` elements.
+    ///
+    /// Unlike `new`, this does not create any elements: the returned `UnionFind`
+    /// has length zero. Add elements with `push`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        UnionFind {
+            parent: Vec::with_capacity(capacity),
+            rank: Vec::with_capacity(capacity),
+            size: Vec::with_capacity(capacity),
+            by_size: false,
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more elements to be pushed.
+    ///
+    /// **Panics** if the new capacity overflows `usize`.
+    pub fn reserve(&mut self, additional: usize) {
+        self.parent.reserve(additional);
+        self.rank.reserve(additional);
+        self.size.reserve(additional);
+    }
+
+    /// Create a new `UnionFind` of `n` disjoint sets that uses *union by size*:
+    /// when two sets are unified, the smaller set is put below the larger one.
+    ///
+    /// The default, `new`, uses *union by rank*. Both give the same amortized bounds.
+    pub fn new_by_size(n: usize) -> Self {
+        UnionFind {
+            by_size: true,
+            ..UnionFind::new(n)
+        }
+    }
+
+    /// Add a new element in a set of its own and return its index.
+    ///
+    /// **Panics** if the `UnionFind` is at the maximum number of elements for its index
+    /// type `K`.
+    pub fn push(&mut self) -> K {
+        let n = self.parent.len();
+        assert!(
+            n <= <K as IndexType>::max().index(),
+            "UnionFind::push: element index type overflowed"
+        );
+        let x = K::new(n);
+        self.parent.push(x);
+        self.rank.push(0);
+        self.size.push(1);
+        x
+    }
+
+    /// Put every element back in a set of its own, keeping the number of elements.
+    ///
+    /// This reuses the existing allocation. Use `clear` to remove the elements instead.
+    pub fn reset(&mut self) {
+        for (ix, p) in self.parent.iter_mut().enumerate() {
+            *p = K::new(ix);
+        }
+        for r in &mut self.rank {
+            *r = 0;
+        }
+        for s in &mut self.size {
+            *s = 1;
+        }
+    }
+
+    /// Remove all elements, keeping the allocated capacity.
+    ///
+    /// Unlike `reset`, which puts every element back in a set of its own, this leaves
+    /// the `UnionFind` with length zero. Add elements again with `push`.
+    pub fn clear(&mut self) {
+        self.parent.clear();
+        self.rank.clear();
+        self.size.clear();
+    }
+
+    /// Return the number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Return `true` if there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
     }
 
     /// Return the representative for `x`.
@@ -69,6 +232,15 @@ where
         }
     }
 
+    /// Return the representative for `x`, or `None` if `x` is out of bounds.
+    pub fn try_find(&self, x: K) -> Option<K> {
+        if x.index() < self.parent.len() {
+            Some(self.find(x))
+        } else {
+            None
+        }
+    }
+
     /// Return the representative for `x`.
     ///
     /// Write back the found representative, flattening the internal
@@ -77,24 +249,109 @@ where
     /// **Panics** if `x` is out of bounds.
     pub fn find_mut(&mut self, x: K) -> K {
         assert!(x.index() < self.parent.len());
-        unsafe { self.find_mut_recursive(x) }
+        unsafe { self.find_mut_unchecked(x) }
+    }
+
+    /// Return the representative for `x`.
+    ///
+    /// Like `find_mut`, but uses *path halving*: every element on the path to the
+    /// representative is made to point at its grandparent, in a single pass. The
+    /// internal datastructure is flattened less than with `find_mut`, but with fewer
+    /// writes, which can be faster for workloads dominated by lookups.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    pub fn find_halving(&mut self, x: K) -> K {
+        assert!(x.index() < self.parent.len());
+        unsafe {
+            let mut x = x;
+            let mut parent = *get_unchecked(&self.parent, x.index());
+            while parent != x {
+                let grandparent = *get_unchecked(&self.parent, parent.index());
+                *get_unchecked_mut(&mut self.parent, x.index()) = grandparent;
+                x = grandparent;
+                parent = *get_unchecked(&self.parent, x.index());
+            }
+            x
+        }
     }
 
-    unsafe fn find_mut_recursive(&mut self, mut x: K) -> K {
-        let mut parent = *get_unchecked(&self.parent, x.index());
-        while parent != x {
-            let grandparent = *get_unchecked(&self.parent, parent.index());
-            *get_unchecked_mut(&mut self.parent, x.index()) = grandparent;
+    unsafe fn find_mut_unchecked(&mut self, x: K) -> K {
+        // First pass: walk up to the representative.
+        let mut root = x;
+        loop {
+            let parent = *get_unchecked(&self.parent, root.index());
+            if parent == root {
+                break;
+            }
+            root = parent;
+        }
+        // Second pass: point every element on the path directly at the representative.
+        let mut x = x;
+        while x != root {
+            let parent = *get_unchecked(&self.parent, x.index());
+            *get_unchecked_mut(&mut self.parent, x.index()) = root;
             x = parent;
-            parent = grandparent;
         }
-        x
+        root
+    }
+
+    /// Return the number of elements in the set containing `x`.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    pub fn set_size(&mut self, x: K) -> usize {
+        let xrep = self.find_mut(x);
+        self.size[xrep.index()]
+    }
+
+    /// Return the number of disjoint sets.
+    ///
+    /// This counts the representatives in a single pass. It does not flatten the internal
+    /// datastructure.
+    pub fn set_count(&self) -> usize {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|&(i, p)| p.index() == i)
+            .count()
+    }
+
+    /// Return the representative of each disjoint set with the number of its elements,
+    /// largest sets first.
+    ///
+    /// Sets of equal size are ordered by their representative. Every element is made to
+    /// point directly at its representative in the process.
+    pub fn roots_by_size(&mut self) -> Vec<(K, usize)> {
+        self.compress();
+        let mut roots: Vec<(K, usize)> = self
+            .parent
+            .iter()
+            .enumerate()
+            .filter(|&(i, p)| p.index() == i)
+            .map(|(i, &p)| (p, self.size[i]))
+            .collect();
+        roots.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.index().cmp(&b.0.index())));
+        roots
     }
 
     /// Returns `true` if the given elements belong to the same set, and returns
     /// `false` otherwise.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
     pub fn equiv(&self, x: K, y: K) -> bool {
-        self.find(x) == self.find(y)
+        let xrep = self.find(x);
+        x == y || xrep == self.find(y)
+    }
+
+    /// Returns `true` if the given elements belong to the same set, and returns
+    /// `false` otherwise.
+    ///
+    /// Like `equiv`, but uses `find_mut`, flattening the internal datastructure
+    /// in the process.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    pub fn equiv_mut(&mut self, x: K, y: K) -> bool {
+        let xrep = self.find_mut(x);
+        x == y || xrep == self.find_mut(y)
     }
 
     /// Unify the two sets containing `x` and `y`.
@@ -103,6 +360,23 @@ where
     ///
     /// **Panics** if `x` or `y` is out of bounds.
     pub fn union(&mut self, x: K, y: K) -> bool {
+        self.union_with(x, y, |_, _| ())
+    }
+
+    /// Unify the two sets containing `x` and `y`.
+    ///
+    /// If the sets are not already the same, `on_merge(winner, loser)` is called with
+    /// the representatives of both sets right before they are unified: `winner` will be
+    /// the representative of the unified set. This can be used to fold data kept for
+    /// the `loser` into the data of the `winner`.
+    ///
+    /// Return `false` if the sets were already the same, `true` if they were unified.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    pub fn union_with<F>(&mut self, x: K, y: K, mut on_merge: F) -> bool
+    where
+        F: FnMut(K, K),
+    {
         if x == y {
             return false;
         }
@@ -113,34 +387,528 @@ where
             return false;
         }
 
+        let (winner, loser) = self.order(xrep, yrep);
+        on_merge(winner, loser);
+        self.link(winner, loser);
+        true
+    }
+
+    /// Unify the two sets containing `x` and `y`.
+    ///
+    /// Return `None` if the sets were already the same, otherwise `Some((winner, loser))`
+    /// with the representatives of both sets before they were unified: `winner` is the
+    /// representative of the unified set, and `loser` now points to it. These are the same
+    /// as the arguments `union_with` passes to its callback.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    pub fn union_full(&mut self, x: K, y: K) -> Option<(K, K)> {
+        let mut merged = None;
+        self.union_with(x, y, |winner, loser| merged = Some((winner, loser)));
+        merged
+    }
+
+    // Return the representatives as `(winner, loser)`, where the set of `loser`
+    // should be put below the set of `winner`.
+    fn order(&self, xrep: K, yrep: K) -> (K, K) {
         let xrepu = xrep.index();
         let yrepu = yrep.index();
-        let xrank = self.rank[xrepu];
-        let yrank = self.rank[yrepu];
-
-        // The rank corresponds roughly to the depth of the treeset, so put the
-        // smaller set below the larger
-        match xrank.cmp(&yrank) {
-            Ordering::Less => self.parent[xrepu] = yrep,
-            Ordering::Greater => self.parent[yrepu] = xrep,
-            Ordering::Equal => {
-                self.parent[yrepu] = xrep;
-                self.rank[xrepu] += 1;
-            }
+        let x_is_smaller = if self.by_size {
+            // Put the set with fewer elements below the larger
+            self.size[xrepu] < self.size[yrepu]
+        } else {
+            // The rank corresponds roughly to the depth of the treeset, so put the
+            // smaller set below the larger
+            self.rank[xrepu] < self.rank[yrepu]
+        };
+        if x_is_smaller {
+            (yrep, xrep)
+        } else {
+            (xrep, yrep)
+        }
+    }
+
+    fn link(&mut self, winner: K, loser: K) {
+        let w = winner.index();
+        let l = loser.index();
+        self.parent[l] = winner;
+        self.size[w] += self.size[l];
+        if !self.by_size && self.rank[w] == self.rank[l] {
+            self.rank[w] = self.rank[w].saturating_add(1);
+            debug_assert!(u32::from(self.rank[w]) <= log2(self.size[w]));
+        }
+    }
+
+    /// Unify the two sets containing `x` and `y`.
+    ///
+    /// Return `None` if `x` or `y` is out of bounds, otherwise the same as `union`.
+    pub fn try_union(&mut self, x: K, y: K) -> Option<bool> {
+        if x.index() < self.parent.len() && y.index() < self.parent.len() {
+            Some(self.union(x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Unify the sets of `self` with the sets of `other`.
+    ///
+    /// Afterwards, two elements are in the same set if they were in the same set
+    /// of either `self` or `other`, or are connected through a chain of such sets.
+    /// This is the same as having done the unions of both in one `UnionFind`.
+    ///
+    /// **Panics** if `self` and `other` have a different number of elements.
+    pub fn merge(&mut self, other: &UnionFind<K>) {
+        assert!(
+            self.len() == other.len(),
+            "UnionFind::merge: number of elements differs"
+        );
+        for ix in 0..other.len() {
+            let x = K::new(ix);
+            self.union(x, other.find(x));
         }
-        true
     }
 
     /// Return a vector mapping each element to its representative.
     pub fn into_labeling(mut self) -> Vec<K> {
+        self.compress();
+        self.parent
+    }
+
+    /// Return an iterator of the elements in the set containing `x`, in increasing order.
+    ///
+    /// This scans all elements, so it takes **O(n)** time; to enumerate every set,
+    /// use `into_groups` instead.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    pub fn members_of(&mut self, x: K) -> impl Iterator<Item = K> + '_ {
+        let xrep = self.find_mut(x);
+        (0..self.parent.len())
+            .map(K::new)
+            .filter(move |&y| self.find_mut(y) == xrep)
+    }
+
+    /// Return the disjoint sets, each as a vector of its elements in increasing order.
+    ///
+    /// The sets are ordered by their smallest element.
+    pub fn into_groups(mut self) -> Vec<Vec<K>> {
+        self.compress();
+        self.groups()
+    }
+
+    // Map each element to the number of its set, when the sets are numbered
+    // in order of their smallest element, and return the number of sets.
+    fn dense_labels(&self) -> (Vec<usize>, usize) {
+        let mut label_of_rep = vec![std::usize::MAX; self.parent.len()];
+        let mut count = 0;
+        let mut labels = Vec::with_capacity(self.parent.len());
+        for ix in 0..self.parent.len() {
+            let label = &mut label_of_rep[self.find(K::new(ix)).index()];
+            if *label == std::usize::MAX {
+                *label = count;
+                count += 1;
+            }
+            labels.push(*label);
+        }
+        (labels, count)
+    }
+
+    fn groups(&self) -> Vec<Vec<K>> {
+        let mut group_of_rep = vec![std::usize::MAX; self.parent.len()];
+        let mut groups = Vec::new();
+        for ix in 0..self.parent.len() {
+            let x = K::new(ix);
+            let group = &mut group_of_rep[self.find(x).index()];
+            if *group == std::usize::MAX {
+                *group = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[*group].push(x);
+        }
+        groups
+    }
+
+    /// Return a slice mapping each element to its representative.
+    ///
+    /// Every element is made to point directly at its representative in the process.
+    pub fn labeling(&mut self) -> &[K] {
+        self.compress();
+        &self.parent
+    }
+
+    /// Return a vector mapping each element to the number of its set, and the
+    /// number of sets *k*.
+    ///
+    /// Unlike the representatives, the set numbers are dense: they are *0* to *k - 1*,
+    /// in order of the smallest element of each set.
+    pub fn relabel(&mut self) -> (Vec<usize>, usize) {
+        self.compress();
+        self.dense_labels()
+    }
+
+    /// Return a vector mapping each element to the smallest element of its set.
+    ///
+    /// Unlike the representatives, these labels do not depend on the order of the unions.
+    pub fn canonicalize_min(&mut self) -> Vec<K> {
+        self.compress();
+        let mut min_of_rep = vec![std::usize::MAX; self.parent.len()];
+        self.parent
+            .iter()
+            .enumerate()
+            .map(|(ix, rep)| {
+                let min = &mut min_of_rep[rep.index()];
+                // the elements are taken in increasing order
+                if *min == std::usize::MAX {
+                    *min = ix;
+                }
+                K::new(*min)
+            })
+            .collect()
+    }
+
+    fn compress(&mut self) {
         // write in the labeling of each element
         unsafe {
             for ix in 0..self.parent.len() {
                 let k = *get_unchecked(&self.parent, ix);
-                let xrep = self.find_mut_recursive(k);
+                let xrep = self.find_mut_unchecked(k);
                 *self.parent.get_unchecked_mut(ix) = xrep;
             }
         }
-        self.parent
+    }
+}
+
+/// Format the disjoint sets, like `{0, 3, 5} {1} {2, 4}`.
+impl<K> fmt::Debug for UnionFind<K>
+where
+    K: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, group) in self.groups().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.debug_set().entries(group).finish()?;
+        }
+        Ok(())
+    }
+}
+
+/// Two `UnionFind` are equal if they have the same number of elements and partition them
+/// into the same sets, regardless of their representatives or internal layout.
+///
+/// Computes in **O(n)** time, ignoring the cost of `find`.
+impl<K> PartialEq for UnionFind<K>
+where
+    K: IndexType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.dense_labels().0 == other.dense_labels().0
+    }
+}
+
+impl<K> Eq for UnionFind<K> where K: IndexType {}
+
+/// Create a new, empty `UnionFind`.
+impl<K> Default for UnionFind<K>
+where
+    K: IndexType,
+{
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// `RollbackUnionFind<K>` is a disjoint-set data structure that can undo its unions.
+///
+/// It tracks set membership of *n* elements indexed from *0* to *n - 1*, like
+/// [`UnionFind`], and records every union in a log. Take a token with `snapshot` and
+/// pass it to `rollback` to revert all unions done since.
+///
+/// To make unions revertible, it does not use path compression, so operations take
+/// **O(log n)** time.
+#[derive(Debug, Clone)]
+pub struct RollbackUnionFind<K> {
+    parent: Vec<K>,
+    rank: Vec<u8>,
+    // For each union: the representative that was put below the other, and whether
+    // the rank of the other was increased.
+    log: Vec<(K, bool)>,
+}
+
+impl<K> RollbackUnionFind<K>
+where
+    K: IndexType,
+{
+    /// Create a new `RollbackUnionFind` of `n` disjoint sets.
+    pub fn new(n: usize) -> Self {
+        RollbackUnionFind {
+            parent: (0..n).map(K::new).collect(),
+            rank: vec![0; n],
+            log: Vec::new(),
+        }
+    }
+
+    /// Return the number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Return `true` if there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Return the representative for `x`.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    pub fn find(&self, x: K) -> K {
+        let mut x = x;
+        loop {
+            let xparent = self.parent[x.index()];
+            if xparent == x {
+                return x;
+            }
+            x = xparent;
+        }
+    }
+
+    /// Returns `true` if the given elements belong to the same set, and returns
+    /// `false` otherwise.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    pub fn equiv(&self, x: K, y: K) -> bool {
+        let xrep = self.find(x);
+        x == y || xrep == self.find(y)
+    }
+
+    /// Unify the two sets containing `x` and `y`.
+    ///
+    /// Return `false` if the sets were already the same, `true` if they were unified.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    pub fn union(&mut self, x: K, y: K) -> bool {
+        let xrep = self.find(x);
+        let yrep = self.find(y);
+        if xrep == yrep {
+            return false;
+        }
+
+        let (winner, loser) = if self.rank[xrep.index()] < self.rank[yrep.index()] {
+            (yrep, xrep)
+        } else {
+            (xrep, yrep)
+        };
+        let w = winner.index();
+        let bumped = self.rank[w] == self.rank[loser.index()];
+        if bumped {
+            self.rank[w] = self.rank[w].saturating_add(1);
+        }
+        self.parent[loser.index()] = winner;
+        self.log.push((loser, bumped));
+        true
+    }
+
+    /// Return a token for the current state, to be passed to `rollback`.
+    pub fn snapshot(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Revert all unions done since `snapshot` returned `token`.
+    ///
+    /// Tokens taken after the snapshot being rolled back to are invalidated.
+    ///
+    /// **Panics** if `token` is newer than the current state.
+    pub fn rollback(&mut self, token: usize) {
+        assert!(
+            token <= self.log.len(),
+            "RollbackUnionFind::rollback: invalid snapshot token"
+        );
+        while self.log.len() > token {
+            let (loser, bumped) = self.log.pop().unwrap();
+            let l = loser.index();
+            if bumped {
+                self.rank[self.parent[l].index()] -= 1;
+            }
+            self.parent[l] = loser;
+        }
+    }
+}
+
+// Serialization representation for UnionFind. Set sizes are not stored: they are
+// recomputed from the parent pointers on deserialization.
+#[cfg(feature = "serde-1")]
+#[derive(Serialize)]
+#[serde(rename = "UnionFind")]
+struct SerUnionFind<'a, K: 'a> {
+    parent: &'a [K],
+    rank: &'a [u8],
+    by_size: bool,
+}
+
+#[cfg(feature = "serde-1")]
+#[derive(Deserialize)]
+#[serde(rename = "UnionFind")]
+struct DeserUnionFind<K> {
+    parent: Vec<K>,
+    rank: Vec<u8>,
+    #[serde(default)]
+    by_size: bool,
+}
+
+/// Requires crate feature `"serde-1"`
+#[cfg(feature = "serde-1")]
+impl<K> serde::Serialize for UnionFind<K>
+where
+    K: IndexType + serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerUnionFind {
+            parent: &self.parent,
+            rank: &self.rank,
+            by_size: self.by_size,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+///
+/// Deserialization fails if the parent pointers do not describe a valid forest.
+#[cfg(feature = "serde-1")]
+impl<'de, K> serde::Deserialize<'de> for UnionFind<K>
+where
+    K: IndexType + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let input = DeserUnionFind::<K>::deserialize(deserializer)?;
+        let (parent, rank) = (input.parent, input.rank);
+        let n = parent.len();
+        if rank.len() != n {
+            return Err(D::Error::custom(format_args!(
+                "invalid length: union find rank length {} does not match parent length {}",
+                rank.len(),
+                n
+            )));
+        }
+        if let Some(p) = parent.iter().find(|p| p.index() >= n) {
+            return Err(D::Error::custom(format_args!(
+                "invalid value: parent `{}` does not exist in union find of length {}",
+                p.index(),
+                n
+            )));
+        }
+
+        // Find the representative of every element, rejecting cycles in the parent pointers,
+        // and count the elements of each set.
+        let mut root = vec![None; n];
+        let mut on_path = vec![false; n];
+        let mut path = Vec::new();
+        let mut size = vec![0; n];
+        for i in 0..n {
+            let mut x = i;
+            while root[x].is_none() && !on_path[x] {
+                on_path[x] = true;
+                path.push(x);
+                let p = parent[x].index();
+                if p == x {
+                    break;
+                }
+                x = p;
+            }
+            let r = match root[x] {
+                Some(r) => r,
+                None if parent[x].index() == x => x,
+                None => {
+                    return Err(D::Error::custom(format_args!(
+                        "invalid value: element `{}` is part of a parent cycle",
+                        x
+                    )))
+                }
+            };
+            for y in path.drain(..) {
+                on_path[y] = false;
+                root[y] = Some(r);
+            }
+            size[r] += 1;
+        }
+        if let Some(r) =
+            (0..n).find(|&r| parent[r].index() == r && u32::from(rank[r]) > log2(size[r]))
+        {
+            return Err(D::Error::custom(format_args!(
+                "invalid value: rank {} of element `{}` is too large for a set of {} elements",
+                rank[r], r, size[r]
+            )));
+        }
+
+        Ok(UnionFind {
+            parent,
+            rank,
+            size,
+            by_size: input.by_size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn find_mut_long_chain() {
+        // Such a chain can't be built with `union`, so build it by hand.
+        let n = 1_000_000;
+        let mut u = UnionFind::<u32>::new(n);
+        for i in 0..n - 1 {
+            u.parent[i] = i as u32 + 1;
+        }
+        let root = n as u32 - 1;
+        assert_eq!(u.find(0), root);
+        assert_eq!(u.find_mut(0), root);
+        assert!(u.parent.iter().all(|&p| p == root));
+    }
+
+    #[test]
+    fn rank_is_logarithmic() {
+        // Balanced unions give the largest possible rank for `n` elements.
+        let n = 1 << 16;
+        let mut u = UnionFind::<u32>::new(n);
+        let mut step = 1;
+        while step < n {
+            for i in (0..n).step_by(2 * step) {
+                assert!(u.union(i as u32, (i + step) as u32));
+            }
+            step *= 2;
+        }
+        let root = u.find(0) as usize;
+        assert_eq!(u.rank[root], 16);
+        assert!(u.rank.iter().all(|&r| r <= 16));
+        assert!(u.rank[root] < 64);
+    }
+
+    #[test]
+    fn find_halving_long_chain() {
+        let n = 1_000_000;
+        let mut u = UnionFind::<u32>::new(n);
+        for i in 0..n - 1 {
+            u.parent[i] = i as u32 + 1;
+        }
+        let root = n as u32 - 1;
+        assert_eq!(u.find_halving(0), root);
+        // every other element on the path now points at its former grandparent
+        assert_eq!(u.parent[0], 2);
+        assert_eq!(u.parent[1], 2);
+        assert_eq!(u.parent[2], 4);
+        for i in 0..n as u32 {
+            assert_eq!(u.find_halving(i), root);
+        }
     }
 }
@@ -282,3 +282,48 @@ fn floyd_warshall_negative_cycle() {
 
     assert!(res.is_err());
 }
+
+#[test]
+fn floyd_warshall_unreachable_with_negative_edge() {
+    //  a --2--> b --(-1)--> c --3--> d
+    //  |                    ^
+    //   ---------4----------
+    let graph = Graph::<(), i32>::from_edges(&[(0, 1, 2), (1, 2, -1), (0, 2, 4), (2, 3, 3)]);
+    let res = floyd_warshall(&graph, |edge| *edge.weight()).unwrap();
+
+    let inf = std::i32::MAX;
+    let expected = [
+        [0, 2, 1, 4],
+        [inf, 0, -1, 2],
+        [inf, inf, 0, 3],
+        [inf, inf, inf, 0],
+    ];
+    assert_eq!(res.len(), 16);
+    for i in graph.node_indices() {
+        for j in graph.node_indices() {
+            assert_eq!(res[&(i, j)], expected[i.index()][j.index()], "{:?}", (i, j));
+        }
+    }
+}
+
+#[test]
+fn floyd_warshall_self_loops_and_parallel_edges() {
+    // a negative self loop is a negative cycle
+    let graph = Graph::<(), i32>::from_edges(&[(0, 1, 1), (1, 1, -1)]);
+    assert!(floyd_warshall(&graph, |edge| *edge.weight()).is_err());
+
+    // a positive self loop does not change the distance to the node itself, and the
+    // cheapest of parallel edges is used, in any order
+    let graph = Graph::<(), i32>::from_edges(&[(0, 0, 5), (0, 1, 1), (0, 1, 7), (1, 0, 3)]);
+    let res = floyd_warshall(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res[&(n(0), n(0))], 0);
+    assert_eq!(res[&(n(0), n(1))], 1);
+    let graph = UnGraph::<(), i32>::from_edges(&[(0, 1, 7), (1, 0, 2)]);
+    let res = floyd_warshall(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res[&(n(0), n(1))], 2);
+    assert_eq!(res[&(n(1), n(0))], 2);
+}
+
+fn n(i: usize) -> NodeIndex {
+    NodeIndex::new(i)
+}
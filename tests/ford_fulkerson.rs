@@ -119,3 +119,88 @@ fn test_ford_fulkerson() {
     let (max_flow, _) = ford_fulkerson(&graph, source, destination);
     assert_eq!(19, max_flow);
 }
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn test_ford_fulkerson_stable_graph() {
+    use petgraph::prelude::StableGraph;
+
+    // remove a node with edges, so that indices of both have holes
+    let mut graph = StableGraph::<(), u32>::from_edges(&[
+        (0, 1, 4),
+        (0, 2, 5),
+        (3, 0, 2),
+        (1, 2, 5),
+        (1, 4, 3),
+        (2, 4, 5),
+    ]);
+    graph.remove_node(3.into());
+    let (max_flow, flows) = ford_fulkerson(&graph, 0.into(), 4.into());
+    assert_eq!(max_flow, 8);
+    assert_eq!(flows.len(), 6);
+}
+
+#[test]
+fn test_ford_fulkerson_antiparallel() {
+    use petgraph::visit::EdgeRef;
+    use petgraph::Direction;
+
+    // edges in both directions between 1 and 2, and an edge of zero capacity
+    let graph = Graph::<(), u32>::from_edges(&[
+        (0, 1, 10),
+        (0, 2, 2),
+        (1, 2, 6),
+        (2, 1, 3),
+        (1, 3, 4),
+        (2, 3, 9),
+        (0, 3, 0),
+    ]);
+    let (max_flow, flows) = ford_fulkerson(&graph, 0.into(), 3.into());
+    assert_eq!(max_flow, 12);
+    assert_eq!(flows[6], 0);
+    for edge in graph.edge_references() {
+        assert!(flows[edge.id().index()] <= *edge.weight());
+    }
+    for node in [1, 2].iter().map(|&i| i.into()) {
+        let sum = |dir| -> u32 {
+            graph
+                .edges_directed(node, dir)
+                .map(|e| flows[e.id().index()])
+                .sum()
+        };
+        assert_eq!(sum(Direction::Incoming), sum(Direction::Outgoing));
+    }
+    // the flow out of the source reaches the sink
+    let out: u32 = graph.edges(0.into()).map(|e| flows[e.id().index()]).sum();
+    assert_eq!(out, max_flow);
+}
+
+#[test]
+fn test_min_cut() {
+    use petgraph::algo::min_cut;
+    use petgraph::graph::edge_index;
+
+    // Example from CLRS book: the bottlenecks are 1 -> 3, 4 -> 3 and 4 -> 5
+    let graph = Graph::<(), u8>::from_edges(&[
+        (0, 1, 16),
+        (0, 2, 13),
+        (1, 2, 10),
+        (1, 3, 12),
+        (2, 1, 4),
+        (2, 4, 14),
+        (3, 2, 9),
+        (3, 5, 20),
+        (4, 3, 7),
+        (4, 5, 4),
+    ]);
+    let (weight, mut cut) = min_cut(&graph, 0.into(), 5.into());
+    cut.sort();
+    assert_eq!(weight, 23);
+    assert_eq!(cut, vec![edge_index(3), edge_index(8), edge_index(9)]);
+    assert_eq!(cut.iter().map(|&e| graph[e]).sum::<u8>(), weight);
+
+    // no path at all
+    let (weight, cut) = min_cut(&graph, 5.into(), 0.into());
+    assert_eq!(weight, 0);
+    assert!(cut.is_empty());
+}
@@ -1,6 +1,6 @@
 extern crate petgraph;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 use petgraph::prelude::*;
@@ -126,6 +126,32 @@ fn dfs_order() {
     }
 }
 
+#[test]
+fn dfs_post_order_tree() {
+    //      0
+    //    /   \
+    //   1     2
+    //  / \     \
+    // 3   4     5
+    let gr: Graph<(), ()> = Graph::from_edges(&[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+    let mut order = Vec::new();
+    let mut dfs = DfsPostOrder::new(&gr, n(0));
+    while let Some(node) = dfs.next(&gr) {
+        order.push(node);
+    }
+    assert_eq!(order.len(), gr.node_count());
+    assert_eq!(order.last(), Some(&n(0)));
+    let position = |x| order.iter().position(|&y| y == x).unwrap();
+    for edge in gr.raw_edges() {
+        assert!(position(edge.target()) < position(edge.source()));
+    }
+
+    // a cycle terminates, and only the reachable nodes are visited
+    let gr: Graph<(), ()> = Graph::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+    let order: Vec<_> = DfsPostOrder::new(&gr, n(1)).iter(&gr).collect();
+    assert_eq!(order, vec![n(0), n(2), n(1)]);
+}
+
 #[test]
 fn bfs() {
     let mut gr = Graph::new();
@@ -186,6 +212,210 @@ fn selfloop() {
     println!("{:?}", gr);
 }
 
+#[test]
+fn find_edge_direction() {
+    let mut gr = Graph::<_, _>::new();
+    let a = gr.add_node("A");
+    let b = gr.add_node("B");
+    let c = gr.add_node("C");
+    let ab = gr.add_edge(a, b, 1);
+
+    assert!(gr.contains_edge(a, b));
+    assert!(!gr.contains_edge(b, a));
+    assert!(!gr.contains_edge(a, c));
+    assert_eq!(gr.find_edge(a, b), Some(ab));
+    assert_eq!(gr.find_edge(b, a), None);
+    assert_eq!(gr.find_edge(a, c), None);
+    assert_eq!(gr.find_edge_undirected(a, b), Some((ab, Outgoing)));
+    assert_eq!(gr.find_edge_undirected(b, a), Some((ab, Incoming)));
+    assert_eq!(gr.find_edge_undirected(b, c), None);
+    // a node that is not in the graph has no edges
+    assert_eq!(gr.find_edge(n(5), a), None);
+    assert_eq!(gr.find_edge_undirected(n(5), a), None);
+
+    // the first matching edge is found
+    let ab2 = gr.add_edge(a, b, 2);
+    assert!(gr.find_edge(a, b) == Some(ab) || gr.find_edge(a, b) == Some(ab2));
+
+    let mut un = gr.into_undirected();
+    un.remove_edge(ab2);
+    assert!(un.contains_edge(b, a));
+    assert_eq!(un.find_edge(b, a), Some(ab));
+    assert_eq!(un.find_edge(a, b), Some(ab));
+}
+
+#[test]
+fn degree_counts() {
+    let mut gr = Graph::<_, ()>::new();
+    let a = gr.add_node("A");
+    let b = gr.add_node("B");
+    let c = gr.add_node("C");
+    gr.add_edge(a, b, ());
+    gr.add_edge(a, b, ());
+    gr.add_edge(c, a, ());
+    gr.add_edge(a, a, ());
+    assert_eq!((gr.out_degree(a), gr.in_degree(a), gr.degree(a)), (3, 2, 5));
+    assert_eq!((gr.out_degree(b), gr.in_degree(b), gr.degree(b)), (0, 2, 2));
+    assert_eq!((gr.out_degree(c), gr.in_degree(c), gr.degree(c)), (1, 0, 1));
+    assert_eq!(gr.degree(n(3)), 0);
+
+    // the degree agrees with the undirected edge iterator, except for self loops
+    let un = gr.clone().into_edge_type::<Undirected>();
+    for nx in un.node_indices() {
+        let loops = un.edges(nx).filter(|e| e.source() == e.target()).count();
+        assert_eq!(un.degree(nx), un.edges(nx).count() + loops);
+        assert_eq!(un.degree(nx), gr.degree(nx));
+    }
+
+    gr.remove_node(b);
+    assert_eq!((gr.out_degree(a), gr.in_degree(a), gr.degree(a)), (1, 2, 3));
+}
+
+#[test]
+fn contract_edge() {
+    use petgraph::graph::edge_index;
+
+    // contracting one edge of a triangle
+    let mut gr = UnGraph::<&str, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 0, 3)]);
+    gr[n(0)] = "a";
+    gr[n(1)] = "b";
+    gr[n(2)] = "c";
+    let ab = gr.find_edge(n(0), n(1)).unwrap();
+    let merged = gr
+        .contract_edge(ab, |a, b| if a < b { a } else { b })
+        .unwrap();
+    assert_eq!(gr.node_count(), 2);
+    assert_eq!(gr[merged], "a");
+    let c = gr.node_indices().find(|&i| i != merged).unwrap();
+    assert_eq!(gr[c], "c");
+    let mut weights: Vec<_> = gr
+        .edges_connecting(merged, c)
+        .map(|e| *e.weight())
+        .collect();
+    weights.sort();
+    assert_eq!(weights, vec![2, 3]);
+
+    // under deduplication, a single edge is left
+    gr.retain_edges(|g, e| *g.edge_weight(e).unwrap() != 2);
+    assert_eq!(gr.edge_count(), 1);
+
+    // a directed graph: the source is kept, and the last node takes the index of the target
+    let mut gr = Graph::<u32, ()>::new();
+    for i in 0..5 {
+        gr.add_node(i);
+    }
+    gr.extend_with_edges(&[(1, 3), (3, 1), (4, 1), (3, 4), (0, 3), (1, 1)]);
+    let e = gr.find_edge(n(1), n(3)).unwrap();
+    let merged = gr.contract_edge(e, |a, b| 10 * a + b).unwrap();
+    assert_eq!(gr.node_count(), 4);
+    assert_eq!(gr[merged], 13);
+    let find = |w| gr.node_indices().find(|&i| gr[i] == w).unwrap();
+    let (zero, four) = (find(0), find(4));
+    assert_eq!(gr.edges_connecting(merged, merged).count(), 2);
+    assert!(gr.contains_edge(four, merged));
+    assert!(gr.contains_edge(merged, four));
+    assert!(gr.contains_edge(zero, merged));
+    assert_eq!(gr.edge_count(), 5);
+    assert_eq!((merged, zero, four), (n(1), n(0), n(3)));
+    // the last edge takes the index of `e`, and the moved edges keep theirs
+    assert_eq!(gr.edge_endpoints(edge_index(0)), Some((merged, merged)));
+    assert_eq!(gr.edge_endpoints(edge_index(3)), Some((merged, four)));
+    assert_eq!(gr.edge_endpoints(edge_index(4)), Some((zero, merged)));
+
+    // a self loop is removed
+    let l = gr.find_edge(merged, merged).unwrap();
+    assert_eq!(gr.contract_edge(l, |_, _| unreachable!()), Some(merged));
+    assert_eq!(gr.edge_count(), 4);
+    assert_eq!(gr.node_count(), 4);
+
+    let missing = edge_index(4);
+    assert_eq!(gr.contract_edge(missing, |a, _| a), None);
+}
+
+#[test]
+fn into_undirected_directed() {
+    use petgraph::algo::connected_components;
+
+    // an antiparallel pair, a path against the edge directions, and an isolated node
+    let mut gr = Graph::<_, _>::new();
+    for w in 0..6 {
+        gr.add_node(w);
+    }
+    gr.extend_with_edges(&[
+        (0, 1, 'a'),
+        (1, 0, 'b'),
+        (1, 2, 'c'),
+        (3, 2, 'd'),
+        (3, 4, 'e'),
+    ]);
+
+    let un = gr.clone().into_undirected();
+    assert_eq!(un.node_count(), 6);
+    assert_eq!(un.edge_count(), 5);
+    assert_eq!(un.edges_connecting(n(0), n(1)).count(), 2);
+    assert_eq!(un.edge_endpoints(EdgeIndex::new(3)), Some((n(3), n(2))));
+    assert_eq!(un[EdgeIndex::new(3)], 'd');
+    assert_eq!(un[n(5)], 5);
+    assert_eq!(connected_components(&un), 2);
+    assert!(has_path_connecting(&un, n(0), n(4), None));
+    assert!(!has_path_connecting(&gr, n(0), n(4), None));
+
+    // back to directed: the same edges, and the same paths
+    let dg = un.into_directed();
+    assert_eq!(dg.edge_count(), 5);
+    for a in gr.node_indices() {
+        for b in gr.node_indices() {
+            assert_eq!(
+                has_path_connecting(&dg, a, b, None),
+                has_path_connecting(&gr, a, b, None)
+            );
+        }
+        assert_eq!(dg[a], gr[a]);
+    }
+    for e in gr.edge_indices() {
+        assert_eq!(dg.edge_endpoints(e), gr.edge_endpoints(e));
+        assert_eq!(dg[e], gr[e]);
+    }
+}
+
+#[test]
+fn adjacency_matrix() {
+    let mut gr = Graph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 0, 3), (2, 2, 4)]);
+    gr.add_node(());
+    let matrix = gr.to_adjacency_matrix();
+    assert_eq!(matrix.len(), 4);
+    for (i, row) in matrix.iter().enumerate() {
+        assert_eq!(row.len(), 4);
+        for (j, &adjacent) in row.iter().enumerate() {
+            assert_eq!(adjacent, gr.contains_edge(n(i), n(j)));
+        }
+    }
+    // directed: not symmetric
+    assert!(matrix[0][1] && !matrix[1][0]);
+    assert!(matrix[2][2]);
+    let weights = gr.to_weight_matrix(|&w| w);
+    assert_eq!(weights[1][2], Some(2));
+    assert_eq!(weights[2][1], None);
+
+    // undirected: symmetric, and the last of parallel edges gives the weight
+    let mut un = gr.into_undirected();
+    un.add_edge(n(1), n(0), 5);
+    let matrix = un.to_adjacency_matrix();
+    let weights = un.to_weight_matrix(|&w| w);
+    for i in 0..4 {
+        for j in 0..4 {
+            assert_eq!(matrix[i][j], matrix[j][i]);
+            assert_eq!(weights[i][j], weights[j][i]);
+        }
+    }
+    assert!(matrix[1][0]);
+    assert_eq!(weights[0][1], Some(5));
+    assert_eq!(weights[2][2], Some(4));
+    assert!(matrix[3].iter().all(|&e| !e));
+
+    assert!(Graph::<(), ()>::new().to_adjacency_matrix().is_empty());
+}
+
 #[test]
 fn cyclic() {
     let mut gr = Graph::new();
@@ -221,6 +451,26 @@ fn cyclic() {
     assert_graph_consistent(&gr);
 }
 
+#[test]
+fn cyclic_parallel_edges() {
+    let mut gr = Graph::new_undirected();
+    let a = gr.add_node("A");
+    let b = gr.add_node("B");
+    gr.add_edge(a, b, ());
+    assert!(!is_cyclic_undirected(&gr));
+    gr.add_edge(a, b, ());
+    assert!(is_cyclic_undirected(&gr));
+
+    // antiparallel directed edges are a cycle when treated as undirected
+    let mut gr = Graph::new();
+    let a = gr.add_node("A");
+    let b = gr.add_node("B");
+    gr.add_edge(a, b, ());
+    assert!(!is_cyclic_undirected(&gr));
+    gr.add_edge(b, a, ());
+    assert!(is_cyclic_undirected(&gr));
+}
+
 #[test]
 fn bipartite() {
     {
@@ -292,6 +542,261 @@ fn bipartite() {
     }
 }
 
+#[test]
+fn bipartite_coloring() {
+    use petgraph::algo::bipartite_coloring;
+
+    assert_eq!(
+        bipartite_coloring(&UnGraph::<(), ()>::default()),
+        Some(vec![])
+    );
+    let mut gr = UnGraph::<(), ()>::default();
+    gr.add_node(());
+    assert_eq!(bipartite_coloring(&gr), Some(vec![false]));
+
+    // a 4-cycle and, in another component, a path of a directed graph
+    let mut gr = Graph::<(), ()>::from_edges(&[(0, 1), (2, 1), (2, 3), (0, 3), (5, 4), (5, 6)]);
+    let colors = bipartite_coloring(&gr).unwrap();
+    assert_eq!(colors, vec![false, true, false, true, false, true, false]);
+    for edge in gr.raw_edges() {
+        assert_ne!(colors[edge.source().index()], colors[edge.target().index()]);
+    }
+
+    // a triangle in either component
+    gr.add_edge(n(4), n(6), ());
+    assert_eq!(bipartite_coloring(&gr), None);
+    let mut gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(bipartite_coloring(&gr), None);
+    gr.remove_edge(petgraph::graph::edge_index(3));
+    assert!(bipartite_coloring(&gr).is_some());
+    gr.add_edge(n(2), n(2), ());
+    assert_eq!(bipartite_coloring(&gr), None);
+}
+
+#[test]
+fn betweenness_centrality() {
+    use petgraph::algo::betweenness_centrality;
+
+    // a star: every path between two leaves goes through the center
+    let gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+    assert_eq!(betweenness_centrality(&gr, false), vec![6., 0., 0., 0., 0.]);
+    assert_eq!(betweenness_centrality(&gr, true), vec![1., 0., 0., 0., 0.]);
+
+    // a directed star away from the center has no paths between leaves
+    let mut gr = Graph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+    assert_eq!(betweenness_centrality(&gr, false), vec![0.; 5]);
+    gr.add_edge(n(1), n(0), ());
+    gr.add_edge(n(2), n(0), ());
+    assert_eq!(betweenness_centrality(&gr, false), vec![6., 0., 0., 0., 0.]);
+    assert_eq!(betweenness_centrality(&gr, true), vec![0.5, 0., 0., 0., 0.]);
+
+    // two shortest paths from 0 to 3 share the credit, a self loop changes nothing
+    let mut gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+    gr.add_edge(n(1), n(1), ());
+    assert_eq!(betweenness_centrality(&gr, false), vec![0.5; 4]);
+
+    #[cfg(feature = "stable_graph")]
+    {
+        // vacant indices have centrality 0
+        let mut gr = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+        gr.remove_node(n(0));
+        assert_eq!(betweenness_centrality(&gr, false), vec![0., 0., 1., 0.]);
+    }
+    assert!(betweenness_centrality(&UnGraph::<(), ()>::default(), true).is_empty());
+}
+
+#[test]
+fn k_core() {
+    use petgraph::algo::k_core;
+
+    assert_eq!(k_core(&UnGraph::<(), ()>::default()), vec![]);
+
+    // a clique of size 4
+    let gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    assert_eq!(k_core(&gr), vec![3; 4]);
+
+    // a path, and an isolated node
+    let mut gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+    gr.add_node(());
+    assert_eq!(k_core(&gr), vec![1, 1, 1, 1, 1, 0]);
+
+    // self loops are ignored, parallel edges all count
+    gr.add_edge(n(5), n(5), ());
+    assert_eq!(k_core(&gr)[5], 0);
+    gr.add_edge(n(0), n(1), ());
+    assert_eq!(k_core(&gr), vec![2, 2, 1, 1, 1, 0]);
+
+    // directed edges count in either direction: a 4-clique with a pendant node
+    let gr = Graph::<(), ()>::from_edges(&[(0, 1), (2, 0), (0, 3), (1, 2), (3, 1), (2, 3), (4, 3)]);
+    assert_eq!(k_core(&gr), vec![3, 3, 3, 3, 1]);
+
+    #[cfg(feature = "stable_graph")]
+    {
+        // vacant indices have coreness 0
+        let mut gr = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+        gr.remove_node(n(1));
+        assert_eq!(k_core(&gr), vec![1, 0, 1, 1]);
+    }
+}
+
+#[test]
+fn greedy_coloring() {
+    use petgraph::algo::greedy_coloring;
+
+    let proper = |gr: &UnGraph<(), ()>, colors: &[usize]| {
+        gr.raw_edges()
+            .iter()
+            .filter(|e| e.source() != e.target())
+            .all(|e| colors[e.source().index()] != colors[e.target().index()])
+    };
+
+    assert_eq!(greedy_coloring(&UnGraph::<(), ()>::default()), (vec![], 0));
+
+    // isolated nodes all have the first color
+    let mut gr = UnGraph::<(), ()>::default();
+    gr.add_node(());
+    gr.add_node(());
+    assert_eq!(greedy_coloring(&gr), (vec![0, 0], 1));
+
+    // a triangle
+    let gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    let (colors, count) = greedy_coloring(&gr);
+    assert_eq!(count, 3);
+    assert!(proper(&gr, &colors));
+
+    // a bipartite graph: a cycle of length 6 with a chord, and a star
+    let mut gr = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 0),
+        (0, 3),
+        (6, 7),
+        (6, 8),
+        (6, 9),
+    ]);
+    let (colors, count) = greedy_coloring(&gr);
+    assert!(count <= 2);
+    assert!(proper(&gr, &colors));
+
+    // self loops are ignored
+    gr.add_edge(n(6), n(6), ());
+    gr.add_edge(n(2), n(2), ());
+    let (loops, count) = greedy_coloring(&gr);
+    assert!(count <= 2);
+    assert_eq!(loops, colors);
+
+    // directed edges count in either direction: a directed triangle
+    let gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(greedy_coloring(&gr).1, 3);
+
+    #[cfg(feature = "stable_graph")]
+    {
+        // vacant indices have color 0
+        let mut gr = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+        gr.remove_node(n(0));
+        let (colors, count) = greedy_coloring(&gr);
+        assert_eq!(colors.len(), 4);
+        assert_eq!(colors[0], 0);
+        assert_ne!(colors[1], colors[2]);
+        assert_ne!(colors[2], colors[3]);
+        assert_eq!(count, 2);
+    }
+}
+
+#[test]
+fn transitive_closure() {
+    use petgraph::adj::UnweightedList;
+    use petgraph::algo::tred::transitive_closure;
+
+    // a path: every node reaches the later nodes
+    let gr = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    let tclos: UnweightedList<NodeIndex> = transitive_closure(&gr, false);
+    for i in 0..4 {
+        for j in 0..4 {
+            assert_eq!(tclos.contains_edge(n(i), n(j)), i < j);
+        }
+    }
+    let tclos: UnweightedList<NodeIndex> = transitive_closure(&gr, true);
+    assert_eq!(tclos.edge_count(), 10);
+    assert!((0..4).all(|i| tclos.contains_edge(n(i), n(i))));
+
+    // nodes on a cycle, or with a self loop, reach themselves
+    let mut gr = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1), (2, 3)]);
+    gr.add_edge(n(3), n(3), ());
+    let tclos: UnweightedList<NodeIndex> = transitive_closure(&gr, false);
+    assert_eq!(
+        tclos.neighbors(n(0)).collect::<Vec<_>>(),
+        vec![n(1), n(2), n(3)]
+    );
+    assert_eq!(
+        tclos.neighbors(n(1)).collect::<Vec<_>>(),
+        vec![n(1), n(2), n(3)]
+    );
+    assert_eq!(tclos.neighbors(n(3)).collect::<Vec<_>>(), vec![n(3)]);
+
+    #[cfg(feature = "stable_graph")]
+    {
+        use petgraph::visit::NodeCount;
+
+        // vacant indices have no edges
+        let mut gr = StableGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+        gr.remove_node(n(1));
+        gr.add_edge(n(0), n(2), ());
+        let tclos: UnweightedList<NodeIndex> = transitive_closure(&gr, true);
+        assert_eq!(tclos.node_count(), 3);
+        assert_eq!(tclos.neighbors(n(0)).collect::<Vec<_>>(), vec![n(0), n(2)]);
+        assert_eq!(tclos.neighbors(n(1)).count(), 0);
+    }
+}
+
+#[test]
+fn eulerian_path() {
+    use petgraph::algo::eulerian_path;
+    use petgraph::graph::edge_index as e;
+
+    assert_eq!(eulerian_path(&UnGraph::<(), ()>::default()), Some(vec![]));
+
+    // a path has two odd nodes, its ends
+    let mut gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(eulerian_path(&gr), Some(vec![e(0), e(1), e(2)]));
+    // closing it makes a circuit, and an isolated node is ignored
+    gr.add_edge(n(3), n(0), ());
+    gr.add_node(());
+    assert_eq!(eulerian_path(&gr), Some(vec![e(0), e(1), e(2), e(3)]));
+
+    // four odd nodes
+    let gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    assert_eq!(eulerian_path(&gr), None);
+
+    // even degrees, but two separate triangles
+    let gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(eulerian_path(&gr), None);
+
+    // a directed path must follow the edges: start at 2, the node with more outgoing edges,
+    // and splice in the cycle of node 0
+    let mut gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 0), (2, 0), (0, 3)]);
+    assert_eq!(eulerian_path(&gr), Some(vec![e(2), e(0), e(1), e(3)]));
+    gr.add_edge(n(2), n(3), ());
+    assert_eq!(eulerian_path(&gr), None);
+
+    // self loops and parallel edges are all used
+    let mut gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 1), (1, 1)]);
+    assert_eq!(eulerian_path(&gr).map(|path| path.len()), Some(3));
+    gr.add_edge(n(0), n(1), ());
+    assert_eq!(eulerian_path(&gr).map(|path| path.len()), Some(4));
+
+    #[cfg(feature = "stable_graph")]
+    {
+        // vacant indices
+        let mut gr = StableGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+        gr.remove_node(n(0));
+        assert_eq!(eulerian_path(&gr), Some(vec![e(1), e(2), e(3)]));
+    }
+}
+
 #[test]
 fn multi() {
     let mut gr = Graph::new();
@@ -366,6 +871,184 @@ fn iter_multi_undirected_edges() {
     assert!(connecting_edges.is_empty());
 }
 
+#[test]
+fn edges_connecting_unconnected() {
+    let mut gr = Graph::<_, _>::from_edges(&[(0, 1, 1), (0, 1, 2), (1, 2, 3), (3, 3, 4)]);
+    gr.add_node(());
+    // no edges between unconnected pairs, or against the direction of the edges
+    assert_eq!(gr.edges_connecting(n(0), n(2)).count(), 0);
+    assert_eq!(gr.edges_connecting(n(1), n(0)).count(), 0);
+    assert_eq!(gr.edges_connecting(n(4), n(4)).count(), 0);
+    assert_eq!(gr.edges_connecting(n(3), n(3)).count(), 1);
+
+    // remove one of the parallel edges by its weight
+    let e = gr
+        .edges_connecting(n(0), n(1))
+        .find(|e| *e.weight() == 1)
+        .map(|e| e.id())
+        .unwrap();
+    gr.remove_edge(e);
+    let weights: Vec<_> = gr
+        .edges_connecting(n(0), n(1))
+        .map(|e| *e.weight())
+        .collect();
+    assert_eq!(weights, vec![2]);
+
+    let un = gr.into_undirected();
+    assert_eq!(un.edges_connecting(n(1), n(0)).count(), 1);
+    assert_eq!(un.edges_connecting(n(2), n(1)).count(), 1);
+    assert_eq!(un.edges_connecting(n(0), n(2)).count(), 0);
+}
+
+#[test]
+fn self_loops_parallel_edges() {
+    use petgraph::graph::edge_index as e;
+
+    // a multigraph with a self loop, an edge three times, and an antiparallel edge
+    let mut gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 1), (1, 2), (0, 1), (1, 0), (0, 1)]);
+    assert!(!gr.is_simple());
+    assert_eq!(gr.self_loops().collect::<Vec<_>>(), vec![e(1)]);
+    let mut pairs: Vec<_> = gr.parallel_edges().collect();
+    pairs.sort();
+    assert_eq!(pairs, vec![(e(0), e(3)), (e(0), e(5)), (e(3), e(5))]);
+
+    // undirected, the antiparallel edge is parallel too
+    let un = gr.clone().into_undirected();
+    assert_eq!(un.self_loops().count(), 1);
+    assert_eq!(un.parallel_edges().count(), 6);
+    assert!(un.parallel_edges().all(|(a, b)| a < b));
+
+    gr.retain_edges(|g, ex| {
+        let (a, b) = g.edge_endpoints(ex).unwrap();
+        a != b && !(a == n(0) && ex != e(0))
+    });
+    assert_eq!(gr.edge_count(), 3);
+    assert!(gr.is_simple());
+    assert!(!gr.into_undirected().is_simple());
+    assert!(Graph::<(), ()>::new().is_simple());
+}
+
+#[test]
+fn neighbors_directed_all_graphs() {
+    use petgraph::visit::{IntoNeighborsDirected, Reversed};
+
+    // on an edge a -> b, b has a as predecessor and a has b as successor
+    fn check<G>(g: G, a: G::NodeId, b: G::NodeId, directed: bool)
+    where
+        G: IntoNeighborsDirected,
+        G::NodeId: std::fmt::Debug + PartialEq,
+    {
+        assert_eq!(
+            g.neighbors_directed(b, Incoming).collect::<Vec<_>>(),
+            vec![a]
+        );
+        assert_eq!(
+            g.neighbors_directed(a, Outgoing).collect::<Vec<_>>(),
+            vec![b]
+        );
+        if directed {
+            assert_eq!(g.neighbors_directed(a, Incoming).count(), 0);
+            assert_eq!(g.neighbors_directed(b, Outgoing).count(), 0);
+        } else {
+            assert_eq!(
+                g.neighbors_directed(a, Incoming).collect::<Vec<_>>(),
+                vec![b]
+            );
+            assert_eq!(
+                g.neighbors_directed(b, Outgoing).collect::<Vec<_>>(),
+                vec![a]
+            );
+        }
+    }
+
+    let gr = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    check(&gr, n(0), n(1), true);
+    check(Reversed(&gr), n(1), n(0), true);
+    check(&gr.clone().into_undirected(), n(0), n(1), false);
+    #[cfg(feature = "stable_graph")]
+    {
+        let sg = StableGraph::<(), ()>::from_edges(&[(0, 1)]);
+        check(&sg, n(0), n(1), true);
+    }
+    #[cfg(feature = "graphmap")]
+    {
+        let gm = DiGraphMap::<u8, ()>::from_edges(&[(0, 1)]);
+        check(&gm, 0, 1, true);
+        let gm = UnGraphMap::<u8, ()>::from_edges(&[(0, 1)]);
+        check(&gm, 0, 1, false);
+    }
+}
+
+#[test]
+fn reserve_capacity() {
+    let mut gr = Graph::<u32, ()>::with_capacity(10, 20);
+    assert_eq!((gr.node_count(), gr.edge_count()), (0, 0));
+    assert!(gr.capacity().0 >= 10 && gr.capacity().1 >= 20);
+    gr.reserve_nodes(100);
+    gr.reserve_edges(200);
+    let (nodes, edges) = gr.capacity();
+    assert!(nodes >= 100 && edges >= 200);
+
+    // filling the reservation does not reallocate, and builds the same graph
+    let mut from_empty = Graph::<u32, ()>::new();
+    for w in 0..100 {
+        gr.add_node(w);
+        from_empty.add_node(w);
+    }
+    for (a, b) in gr.node_indices().zip(gr.node_indices().skip(1)) {
+        gr.add_edge(a, b, ());
+        from_empty.add_edge(a, b, ());
+    }
+    assert_eq!(gr.capacity(), (nodes, edges));
+    assert_eq!(format!("{:?}", gr), format!("{:?}", from_empty));
+}
+
+#[test]
+fn is_equal() {
+    let mut gr = Graph::<char, u32>::new();
+    let a = gr.add_node('a');
+    let b = gr.add_node('b');
+    let c = gr.add_node('c');
+    gr.add_edge(a, b, 1);
+    gr.add_edge(b, c, 2);
+    gr.add_edge(a, b, 3);
+    assert!(gr.is_equal(&gr.clone()));
+
+    // the same edges in another order
+    let mut other = Graph::<char, u32>::new();
+    other.add_node('a');
+    other.add_node('b');
+    other.add_node('c');
+    other.add_edge(a, b, 3);
+    other.add_edge(b, c, 2);
+    other.add_edge(a, b, 1);
+    assert!(gr.is_equal(&other));
+
+    // an edge weight differs
+    other[EdgeIndex::new(1)] = 5;
+    assert!(!gr.is_equal(&other));
+    other[EdgeIndex::new(1)] = 2;
+
+    // parallel edges count, and directions matter for directed graphs
+    let mut reversed = other.clone();
+    reversed.add_edge(a, b, 3);
+    assert!(!gr.is_equal(&reversed));
+    let rev = reversed.find_edge(b, c).unwrap();
+    reversed.remove_edge(EdgeIndex::new(reversed.edge_count() - 1));
+    let w = reversed.remove_edge(rev).unwrap();
+    reversed.add_edge(c, b, w);
+    assert!(!gr.is_equal(&reversed));
+    assert!(gr
+        .clone()
+        .into_undirected()
+        .is_equal(&reversed.into_undirected()));
+
+    // node weights at the same indices
+    other[c] = 'd';
+    assert!(!gr.is_equal(&other));
+    assert!(!gr.is_equal(&Graph::new()));
+}
+
 #[test]
 fn update_edge() {
     {
@@ -434,6 +1117,151 @@ fn dijk() {
     assert_eq!(scores[&c], 9);
 }
 
+#[test]
+fn dijkstra_cheap_first_edge() {
+    // the cheapest edge out of 0 starts the longer path to 4
+    let g = Graph::<(), u32>::from_edges(&[
+        (0, 1, 1),
+        (1, 2, 5),
+        (2, 4, 5),
+        (0, 3, 4),
+        (3, 4, 2),
+        (4, 5, 1),
+    ]);
+    let scores = dijkstra(&g, n(0), None, |e| *e.weight());
+    let expected: HashMap<_, _> = vec![(0, 0), (1, 1), (2, 6), (3, 4), (4, 6), (5, 7)]
+        .into_iter()
+        .map(|(i, s)| (n(i), s))
+        .collect();
+    assert_eq!(scores, expected);
+
+    // nothing leads back to 0
+    let scores = dijkstra(&g, n(3), None, |e| *e.weight());
+    assert_eq!(scores.len(), 3);
+    assert!(!scores.contains_key(&n(0)));
+}
+
+#[test]
+fn bellman_ford_negative_weights() {
+    use petgraph::algo::bellman_ford;
+
+    // the direct edges are cheaper than the first steps of the negative detour
+    let mut g = Graph::<(), f64>::from_edges(&[
+        (0, 1, 4.),
+        (0, 2, 5.),
+        (2, 1, -3.),
+        (1, 3, 2.),
+        (2, 3, 4.),
+        (3, 4, -1.),
+    ]);
+    g.add_node(());
+    let paths = bellman_ford(&g, n(0)).unwrap();
+    assert_eq!(paths.distances[..5], [0., 2., 5., 4., 3.]);
+    assert_eq!(paths.distances[5], f64::INFINITY);
+    assert_eq!(paths.predecessors[0], None);
+    assert_eq!(paths.predecessors[5], None);
+
+    // follow the predecessors back from 4
+    let mut path = vec![n(4)];
+    while let Some(pred) = paths.predecessors[path.last().unwrap().index()] {
+        path.push(pred);
+    }
+    path.reverse();
+    assert_eq!(path, vec![n(0), n(2), n(1), n(3), n(4)]);
+
+    // a negative cycle that is not reachable from the source does not matter
+    g.add_edge(n(5), n(5), -1.);
+    assert!(bellman_ford(&g, n(0)).is_ok());
+    assert!(bellman_ford(&g, n(5)).is_err());
+    g.add_edge(n(4), n(5), 0.);
+    assert!(bellman_ford(&g, n(0)).is_err());
+}
+
+#[test]
+fn find_negative_cycle_three_nodes() {
+    use petgraph::algo::find_negative_cycle;
+
+    // a tail to the cycle 2 -> 3 -> 4 -> 2 of weight -1, and a positive cycle 1 -> 5 -> 1
+    let mut g = Graph::<(), f64>::from_edges(&[
+        (0, 1, 1.),
+        (1, 2, 1.),
+        (2, 3, 2.),
+        (3, 4, -4.),
+        (4, 2, 1.),
+        (1, 5, 1.),
+        (5, 1, 1.),
+    ]);
+    let cycle = find_negative_cycle(&g, n(0)).unwrap();
+    assert_eq!(cycle.len(), 3);
+    let first = cycle.iter().position(|&v| v == n(2)).unwrap();
+    let rotated: Vec<_> = (0..3).map(|i| cycle[(first + i) % 3]).collect();
+    assert_eq!(rotated, vec![n(2), n(3), n(4)]);
+
+    // from a node on the cycle
+    assert_eq!(find_negative_cycle(&g, n(3)).map(|c| c.len()), Some(3));
+    // not reachable from the source
+    g.add_node(());
+    assert_eq!(find_negative_cycle(&g, n(6)), None);
+
+    // a negative self loop, with no other negative cycle
+    let mut g = Graph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, -1.), (2, 2, -0.5)]);
+    assert_eq!(find_negative_cycle(&g, n(0)), Some(vec![n(2)]));
+    g[petgraph::graph::edge_index(2)] = 0.5;
+    assert_eq!(find_negative_cycle(&g, n(0)), None);
+}
+
+#[test]
+fn build_path() {
+    use petgraph::algo::build_path;
+
+    // the path a -> b -> c -> d, and e which is not reachable
+    let mut g = Graph::<(), ()>::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    let e = g.add_node(());
+    let predecessors: HashMap<_, _> = vec![(b, a), (c, b), (d, c)].into_iter().collect();
+    assert_eq!(build_path(&predecessors, d), Some(vec![a, b, c, d]));
+    assert_eq!(build_path(&predecessors, b), Some(vec![a, b]));
+    assert_eq!(build_path(&predecessors, a), None);
+    assert_eq!(build_path(&predecessors, e), None);
+
+    // a malformed map
+    let predecessors: HashMap<_, _> = vec![(a, b), (b, c), (c, a), (d, c)].into_iter().collect();
+    assert_eq!(build_path(&predecessors, d), None);
+    assert_eq!(build_path(&predecessors, a), None);
+}
+
+#[test]
+fn build_path_from_predecessors() {
+    use petgraph::algo::{bellman_ford, build_path_from_predecessors};
+
+    // a -> b -> c is shorter than a -> c, d -> a, and e which is not reachable
+    let mut g = Graph::<(), f64>::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    let e = g.add_node(());
+    g.extend_with_edges(&[(a, b, 1.), (b, c, -0.5), (a, c, 1.), (d, a, 1.), (c, d, 3.)]);
+    let paths = bellman_ford(&g, a).unwrap();
+    let path = |target| build_path_from_predecessors(&g, &paths.predecessors, target);
+    assert_eq!(path(c), Some(vec![a, b, c]));
+    assert_eq!(path(d), Some(vec![a, b, c, d]));
+    assert_eq!(path(a), None);
+    assert_eq!(path(e), None);
+
+    // malformed predecessors
+    let predecessors = vec![Some(c), Some(a), Some(b), Some(c), None];
+    assert_eq!(build_path_from_predecessors(&g, &predecessors, d), None);
+    assert_eq!(build_path_from_predecessors(&g, &predecessors, e), None);
+    assert_eq!(
+        build_path_from_predecessors(&g, &predecessors[..2], d),
+        None
+    );
+}
+
 #[test]
 fn test_astar_null_heuristic() {
     let mut g = Graph::new();
@@ -516,6 +1344,67 @@ fn test_astar_manhattan_heuristic() {
     }
 }
 
+#[test]
+fn test_astar_grid_pruning() {
+    // a 10 x 10 grid with unit edges between horizontal and vertical neighbors
+    let side = 10;
+    let mut g = UnGraph::<(i32, i32), i32>::default();
+    for y in 0..side {
+        for x in 0..side {
+            g.add_node((x, y));
+        }
+    }
+    let at = |x: i32, y: i32| n((y * side + x) as usize);
+    for y in 0..side {
+        for x in 0..side {
+            if x + 1 < side {
+                g.add_edge(at(x, y), at(x + 1, y), 1);
+            }
+            if y + 1 < side {
+                g.add_edge(at(x, y), at(x, y + 1), 1);
+            }
+        }
+    }
+
+    let (start, goal) = (at(0, 0), at(side - 1, 0));
+    let manhattan = |nx: NodeIndex| {
+        let (x1, y1) = g[nx];
+        let (x2, y2) = g[goal];
+        (x2 - x1).abs() + (y2 - y1).abs()
+    };
+    let mut pruned_calls = 0;
+    let pruned = astar(
+        &g,
+        start,
+        |nx| nx == goal,
+        |e| {
+            pruned_calls += 1;
+            *e.weight()
+        },
+        manhattan,
+    );
+    let mut zero_calls = 0;
+    let zero = astar(
+        &g,
+        start,
+        |nx| nx == goal,
+        |e| {
+            zero_calls += 1;
+            *e.weight()
+        },
+        |_| 0,
+    );
+
+    assert_eq!(
+        pruned,
+        Some((side - 1, (0..side).map(|x| at(x, 0)).collect()))
+    );
+    assert_eq!(zero.map(|(cost, _)| cost), Some(side - 1));
+    // the heuristic keeps the search on the bottom row
+    assert!(pruned_calls < zero_calls);
+    assert!(pruned_calls <= 3 * side);
+}
+
 #[test]
 fn test_astar_runtime_optimal() {
     let mut g = Graph::new();
@@ -963,6 +1852,39 @@ fn tarjan_scc() {
     );
 }
 
+#[test]
+fn tarjan_scc_cycle_with_tail() {
+    // the cycle 0 -> 1 -> 2 -> 0, the tail 2 -> 3 -> 4 and a self loop at 4
+    let gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 4)]);
+    let mut sccs = petgraph::algo::tarjan_scc(&gr);
+    for scc in &mut sccs {
+        scc.sort();
+    }
+    assert_eq!(sccs, vec![vec![n(4)], vec![n(3)], vec![n(0), n(1), n(2)]]);
+}
+
+#[test]
+fn scc_deep() {
+    use petgraph::algo::{kosaraju_scc, tarjan_scc};
+
+    // deep enough to overflow the stack if each node needed a call frame
+    let len = 1_000_000;
+    let mut gr = Graph::<(), ()>::with_capacity(len, len);
+    gr.extend_with_edges((1..len as u32).map(|i| (i - 1, i)));
+    for sccs in vec![tarjan_scc(&gr), kosaraju_scc(&gr)] {
+        assert_eq!(sccs.len(), len);
+        assert_eq!(sccs[0], vec![n(len - 1)]);
+        assert_eq!(sccs[len - 1], vec![n(0)]);
+    }
+
+    // closing the path makes it one big cycle
+    gr.add_edge(n(len - 1), n(0), ());
+    for sccs in vec![tarjan_scc(&gr), kosaraju_scc(&gr)] {
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].len(), len);
+    }
+}
+
 #[test]
 fn condensation() {
     let gr: Graph<(), ()> = Graph::from_edges(&[
@@ -1000,6 +1922,25 @@ fn condensation() {
     assert!(cond.edge_count() == gr.edge_count());
 }
 
+#[test]
+fn condensation_cycle() {
+    let gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)])
+        .map(|nx, _| nx.index(), |_, &w| w);
+
+    let cond = petgraph::algo::condensation(gr.clone(), true);
+    assert_eq!(cond.node_count(), 1);
+    assert_eq!(cond.edge_count(), 0);
+    let mut weights = cond[n(0)].clone();
+    weights.sort();
+    assert_eq!(weights, vec![0, 1, 2, 3]);
+
+    // every edge becomes a self loop
+    let cond = petgraph::algo::condensation(gr, false);
+    assert_eq!(cond.node_count(), 1);
+    assert_eq!(cond.edge_count(), 4);
+    assert!(cond.edge_references().all(|e| e.source() == e.target()));
+}
+
 #[test]
 fn connected_comp() {
     let n = NodeIndex::new;
@@ -1037,6 +1978,128 @@ fn connected_comp() {
     assert_eq!(petgraph::algo::connected_components(&gr), 2);
 }
 
+#[test]
+fn connected_comp_cliques() {
+    let mut gr = UnGraph::<(), ()>::new_undirected();
+    assert_eq!(petgraph::algo::connected_components(&gr), 0);
+
+    // two disjoint 4-cliques
+    for _ in 0..8 {
+        gr.add_node(());
+    }
+    for &offset in &[0, 4] {
+        for i in 0..4 {
+            for j in i + 1..4 {
+                gr.add_edge(n(offset + i), n(offset + j), ());
+            }
+        }
+    }
+    assert_eq!(petgraph::algo::connected_components(&gr), 2);
+    gr.add_edge(n(3), n(4), ());
+    assert_eq!(petgraph::algo::connected_components(&gr), 1);
+}
+
+#[test]
+fn weakly_connected_comp() {
+    use petgraph::algo::{connected_components, weakly_connected_components};
+
+    let mut gr = Graph::<(), ()>::new();
+    assert_eq!(weakly_connected_components(&gr), vec![]);
+
+    // a directed path 3 -> 1 -> 4 -> 0, the isolated node 2 and a cycle 5 -> 6 -> 5
+    for _ in 0..7 {
+        gr.add_node(());
+    }
+    gr.extend_with_edges(&[(3, 1), (1, 4), (4, 0), (5, 6), (6, 5)]);
+    let labels = weakly_connected_components(&gr);
+    assert_eq!(labels, vec![0, 0, 1, 0, 0, 2, 2]);
+    assert_eq!(
+        labels.iter().max().map(|&l| l + 1),
+        Some(connected_components(&gr))
+    );
+
+    gr.add_edge(n(2), n(6), ());
+    assert_eq!(weakly_connected_components(&gr), vec![0, 0, 1, 0, 0, 1, 1]);
+
+    #[cfg(feature = "graphmap")]
+    {
+        // labels follow the compact index, the insertion order of a graph map
+        let mut gm = DiGraphMap::<u32, ()>::from_edges(&[(30, 10), (20, 40)]);
+        gm.add_node(0);
+        gm.add_edge(40, 50, ());
+        assert_eq!(weakly_connected_components(&gm), vec![0, 0, 1, 1, 2, 1]);
+    }
+}
+
+#[test]
+fn articulation_points() {
+    use petgraph::algo::articulation_points;
+
+    // a barbell: two triangles, with node 3 adjacent to all their nodes
+    let mut gr = UnGraph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (4, 5),
+        (5, 6),
+        (6, 4),
+        (3, 0),
+        (3, 1),
+        (3, 2),
+        (3, 4),
+        (3, 5),
+        (3, 6),
+    ]);
+    assert_eq!(articulation_points(&gr), vec![n(3)]);
+    // a second link between the triangles
+    gr.add_edge(n(2), n(4), ());
+    assert_eq!(articulation_points(&gr), vec![]);
+
+    // each inner node of a path, in each component, a directed graph
+    let mut gr = Graph::<(), ()>::from_edges(&[(1, 0), (1, 2), (3, 2), (4, 5), (6, 5)]);
+    gr.add_node(());
+    assert_eq!(articulation_points(&gr), vec![n(1), n(2), n(5)]);
+    // parallel edges and self loops do not help
+    gr.extend_with_edges(&[(0, 1), (5, 5), (4, 5)]);
+    assert_eq!(articulation_points(&gr), vec![n(1), n(2), n(5)]);
+
+    assert_eq!(articulation_points(&Graph::<(), ()>::new()), vec![]);
+}
+
+#[test]
+fn articulation_points_deep() {
+    let len = 1_000_000;
+    let mut gr = UnGraph::<(), ()>::with_capacity(len, len);
+    gr.extend_with_edges((1..len as u32).map(|i| (i - 1, i)));
+    let points = petgraph::algo::articulation_points(&gr);
+    assert_eq!(points.len(), len - 2);
+    assert_eq!(points[0], n(1));
+}
+
+#[test]
+fn bridges() {
+    use petgraph::algo::bridges;
+    use petgraph::graph::edge_index as e;
+
+    // every edge of a path
+    let mut gr = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    let mut found = bridges(&gr);
+    found.sort();
+    assert_eq!(found, vec![e(0), e(1), e(2)]);
+
+    // no edge of a cycle
+    gr.add_edge(n(3), n(0), ());
+    assert_eq!(bridges(&gr), vec![]);
+
+    // parallel edges, in either direction, and self loops
+    let gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 0), (1, 2), (2, 2), (2, 3), (2, 3), (4, 3)]);
+    let mut found = bridges(&gr);
+    found.sort();
+    assert_eq!(found, vec![e(2), e(6)]);
+
+    assert_eq!(bridges(&Graph::<(), ()>::new()), vec![]);
+}
+
 #[should_panic]
 #[test]
 fn oob_index() {
@@ -1047,6 +2110,30 @@ fn oob_index() {
     gr[b];
 }
 
+#[should_panic]
+#[test]
+fn oob_edge_index_mut() {
+    let mut gr = Graph::<_, _>::new();
+    let a = gr.add_node(0);
+    let e = gr.add_edge(a, a, 1);
+    gr.remove_edge(e);
+    gr[e] = 2;
+}
+
+#[test]
+fn index_mut_weights() {
+    let mut gr = Graph::<_, _>::new();
+    let a = gr.add_node("a");
+    let b = gr.add_node("b");
+    let e = gr.add_edge(a, b, 1);
+    gr[a] = "A";
+    gr[e] *= 10;
+    assert_eq!(gr.node_weight(a), Some(&"A"));
+    assert_eq!(gr.node_weight(b), Some(&"b"));
+    assert_eq!(gr.edge_weight(e), Some(&10));
+    assert_eq!((gr[a], gr[e]), ("A", 10));
+}
+
 #[test]
 fn usize_index() {
     let mut gr = Graph::<_, _, Directed, usize>::with_capacity(0, 0);
@@ -1619,6 +2706,28 @@ fn map_filter_map() {
     assert_graph_consistent(&g4);
 }
 
+#[test]
+fn retain_even_nodes() {
+    let mut gr = Graph::<u32, ()>::new();
+    for w in 0..6 {
+        gr.add_node(w);
+    }
+    gr.extend_with_edges(&[(0, 1), (0, 2), (2, 4), (3, 4), (4, 4), (5, 0)]);
+    gr.retain_nodes(|g, nx| g[nx] % 2 == 0);
+
+    let mut weights: Vec<_> = gr.node_weights().cloned().collect();
+    weights.sort();
+    assert_eq!(weights, vec![0, 2, 4]);
+    // only the edges between even nodes remain
+    let mut edges: Vec<_> = gr
+        .edge_references()
+        .map(|e| (gr[e.source()], gr[e.target()]))
+        .collect();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 2), (2, 4), (4, 4)]);
+    assert_graph_consistent(&gr);
+}
+
 #[test]
 fn from_edges() {
     let n = NodeIndex::new;
@@ -1631,6 +2740,16 @@ fn from_edges() {
     assert_eq!(gr.neighbors(n(2)).count(), 3);
     assert_eq!(gr.neighbors(n(3)).count(), 3);
     assert_graph_consistent(&gr);
+
+    // nodes are added up to the highest index, with default weights
+    let gr = Graph::<i32, ()>::from_edges(vec![(0, 1), (1, 2)]);
+    assert_eq!(gr.node_count(), 3);
+    assert_eq!(gr.edge_count(), 2);
+    let gr = Graph::<i32, u8>::from_edges(&[(4, 2, 7)]);
+    assert_eq!(gr.node_count(), 5);
+    assert!(gr.node_weights().all(|&w| w == 0));
+    assert_eq!(gr.find_edge(n(4), n(2)).map(|e| gr[e]), Some(7));
+    assert_graph_consistent(&gr);
 }
 
 #[test]
@@ -1891,6 +3010,38 @@ fn filtered() {
     assert_eq!(set(po), set(g.node_identifiers().filter(|n| (filt.1)(*n))));
 }
 
+#[test]
+fn filtered_bfs_dijkstra() {
+    // 0 -> 1 -> 2 -> 3, with a shortcut 0 -> 2 through node 4
+    let g = Graph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 4, 1), (4, 2, 0)]);
+
+    // filtered out nodes disappear from the neighbor lists as well
+    let filt = NodeFiltered::from_fn(&g, |nx: NodeIndex| nx != n(1));
+    assert!(filt.neighbors(n(0)).eq(Some(n(4))));
+    assert_eq!(filt.edges(n(0)).count(), 1);
+
+    let mut bfs = Bfs::new(&filt, n(0));
+    let mut reached = Vec::new();
+    while let Some(nx) = bfs.next(&filt) {
+        reached.push(nx);
+    }
+    assert_eq!(set(reached), set(vec![n(0), n(4), n(2), n(3)]));
+
+    let scores = dijkstra(&filt, n(0), None, |e| *e.weight());
+    assert_eq!(scores.get(&n(1)), None);
+    assert_eq!(scores[&n(3)], 2);
+
+    // without node 4, only node 0 remains reachable
+    let filt = NodeFiltered::from_fn(&g, |nx: NodeIndex| nx != n(1) && nx != n(4));
+    let mut bfs = Bfs::new(&filt, n(0));
+    assert_eq!(bfs.next(&filt), Some(n(0)));
+    assert_eq!(bfs.next(&filt), None);
+    assert_eq!(
+        dijkstra(&filt, n(0), None, |e| *e.weight()),
+        vec![(n(0), 0)].into_iter().collect()
+    );
+}
+
 #[test]
 fn filtered_edge_reverse() {
     use petgraph::visit::EdgeFiltered;
@@ -2450,3 +3601,279 @@ fn test_dominators_simple_fast() {
         "nodes that aren't reachable from the root do not have an idom"
     );
 }
+
+#[test]
+fn graph_builder() {
+    use petgraph::graph::GraphBuilder;
+
+    let mut builder = GraphBuilder::<&str, u32, f32, Undirected>::new();
+    let a = builder.node("a", 1);
+    assert_eq!(builder.node("a", 2), a);
+    let ab = builder.edge("a", "b", 1.);
+    builder.edge("b", "c", 2.);
+    builder.edge("c", "a", 3.);
+    let b = builder.node_index(&"b").unwrap();
+    assert_eq!(builder.node("b", 4), b);
+    assert_eq!(builder.node_index(&"d"), None);
+
+    let graph = builder.build();
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(
+        graph.node_weights().cloned().collect::<Vec<_>>(),
+        vec![2, 4, 0]
+    );
+    assert_eq!(graph.edge_endpoints(ab), Some((a, b)));
+    assert!(!graph.is_directed());
+}
+
+#[test]
+fn induced_subgraph_triangle() {
+    // a triangle 1 -> 2 -> 3 -> 1, inside a larger graph
+    let mut gr = Graph::<_, _>::new();
+    let nodes: Vec<_> = (0..6).map(|i| gr.add_node(i * 10)).collect();
+    gr.add_edge(nodes[0], nodes[1], "01");
+    gr.add_edge(nodes[1], nodes[2], "12");
+    gr.add_edge(nodes[2], nodes[3], "23");
+    gr.add_edge(nodes[3], nodes[1], "31");
+    gr.add_edge(nodes[3], nodes[4], "34");
+    gr.add_edge(nodes[5], nodes[2], "52");
+
+    let (sub, map) = gr.induced_subgraph(&[nodes[3], nodes[1], nodes[2], nodes[3]]);
+    assert_eq!(sub.node_count(), 3);
+    assert_eq!(sub.edge_count(), 3);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map[&nodes[3]], n(0));
+    assert_eq!(map[&nodes[1]], n(1));
+    assert_eq!(map[&nodes[2]], n(2));
+    for (&old, &new) in &map {
+        assert_eq!(gr[old], sub[new]);
+    }
+    let edges: Vec<_> = sub
+        .edge_references()
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            (map[&nodes[1]], map[&nodes[2]], "12"),
+            (map[&nodes[2]], map[&nodes[3]], "23"),
+            (map[&nodes[3]], map[&nodes[1]], "31"),
+        ]
+    );
+
+    let (empty, map) = gr.induced_subgraph(&[]);
+    assert_eq!(empty.node_count(), 0);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn incremental_connectivity() {
+    use petgraph::algo::{connected_components, has_path_connecting, IncrementalConnectivity};
+
+    let mut gr = UnGraph::<(), ()>::new_undirected();
+    let mut connectivity = IncrementalConnectivity::new(0);
+    for _ in 0..6 {
+        assert_eq!(connectivity.add_node(), gr.add_node(()));
+    }
+    assert_eq!(connectivity.component_count(), 6);
+
+    let edges = [(0, 1), (2, 3), (1, 0), (4, 3), (1, 4), (2, 1), (5, 5)];
+    for &(a, b) in &edges {
+        gr.add_edge(n(a), n(b), ());
+        connectivity.add_edge(n(a), n(b));
+        assert_eq!(connectivity.component_count(), connected_components(&gr));
+        for i in 0..6 {
+            for j in 0..6 {
+                let path = has_path_connecting(&gr, n(i), n(j), None);
+                assert_eq!(connectivity.connected(n(i), n(j)), path);
+            }
+        }
+    }
+    assert_eq!(connectivity.component_count(), 2);
+    assert!(!connectivity.connected(n(0), n(5)));
+
+    let rebuilt = IncrementalConnectivity::from_graph(&gr);
+    assert_eq!(rebuilt.component_count(), 2);
+}
+
+#[test]
+fn stoer_wagner_min_cut() {
+    use petgraph::algo::stoer_wagner_min_cut;
+
+    // the example of Stoer and Wagner, "A Simple Min-Cut Algorithm"
+    let mut gr = UnGraph::<(), u32>::from_edges(&[
+        (0, 1, 2),
+        (0, 4, 3),
+        (1, 2, 3),
+        (1, 4, 2),
+        (1, 5, 2),
+        (2, 3, 4),
+        (2, 6, 2),
+        (3, 6, 2),
+        (3, 7, 2),
+        (4, 5, 3),
+        (5, 6, 1),
+        (6, 7, 3),
+    ]);
+    let (weight, mut side) = stoer_wagner_min_cut(&gr, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 4);
+    if side.contains(&n(0)) {
+        side = gr.node_indices().filter(|x| !side.contains(x)).collect();
+    }
+    assert_eq!(side, vec![n(2), n(3), n(6), n(7)]);
+
+    // unit weights count the edges of the cut, here those of node 0 or 7
+    let (weight, side) = stoer_wagner_min_cut(&gr, |_| 1).unwrap();
+    assert_eq!(weight, 2);
+    let crossing = gr
+        .edge_references()
+        .filter(|e| side.contains(&e.source()) != side.contains(&e.target()))
+        .count();
+    assert_eq!(crossing, 2);
+
+    // a node without edges is cut off for free
+    let isolated = gr.add_node(());
+    let (weight, side) = stoer_wagner_min_cut(&gr, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 0);
+    assert!(side == vec![isolated] || side.len() == 8);
+
+    assert_eq!(
+        stoer_wagner_min_cut(&UnGraph::<(), u32>::from_edges(&[(0, 0, 1)]), |_| 1),
+        None
+    );
+}
+
+#[test]
+fn dijkstra_custom_measure() {
+    use std::ops::Add;
+
+    // a cost that saturates instead of overflowing
+    #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+    struct Saturating(u8);
+
+    impl Add for Saturating {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Saturating(self.0.saturating_add(rhs.0))
+        }
+    }
+
+    let gr = Graph::<(), u8>::from_edges(&[(0, 1, 200), (1, 2, 100), (0, 3, 5), (3, 2, 250)]);
+    let scores = dijkstra(&gr, n(0), None, |e| Saturating(*e.weight()));
+    assert_eq!(scores[&n(1)], Saturating(200));
+    assert_eq!(scores[&n(3)], Saturating(5));
+    // both paths to 2 saturate
+    assert_eq!(scores[&n(2)], Saturating(255));
+
+    let path = astar(
+        &gr,
+        n(0),
+        |x| x == n(2),
+        |e| Saturating(*e.weight()),
+        |_| Saturating(0),
+    );
+    assert_eq!(path.map(|(cost, _)| cost), Some(Saturating(255)));
+}
+
+#[test]
+fn cycle_basis() {
+    use petgraph::algo::cycle_basis;
+
+    let square = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let cycles = cycle_basis(&square);
+    assert_eq!(cycles.len(), 1);
+    let mut cycle = cycles[0].clone();
+    assert_eq!(cycle.len(), 4);
+    // consecutive nodes are adjacent, around the cycle
+    for i in 0..4 {
+        assert!(square.contains_edge(cycle[i], cycle[(i + 1) % 4]));
+    }
+    cycle.sort();
+    assert_eq!(cycle, vec![n(0), n(1), n(2), n(3)]);
+
+    // a self loop, a parallel edge, and a tree have cycles of one, two and no nodes
+    let mut gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 1), (1, 0), (1, 2), (2, 3)]);
+    let cycles = cycle_basis(&gr);
+    assert_eq!(cycles.len(), 2);
+    assert!(cycles.contains(&vec![n(1)]));
+    assert!(cycles
+        .iter()
+        .any(|c| c.len() == 2 && c.contains(&n(0)) && c.contains(&n(1))));
+    gr.clear_edges();
+    gr.extend_with_edges(&[(0, 1), (1, 2), (0, 3)]);
+    assert!(cycle_basis(&gr).is_empty());
+}
+
+#[test]
+fn swap_remove_node_relocated() {
+    let mut gr = Graph::<_, _>::new();
+    let nodes: Vec<_> = (0..5).map(|i| gr.add_node(i)).collect();
+    gr.add_edge(nodes[4], nodes[0], "40");
+    gr.add_edge(nodes[2], nodes[4], "24");
+    gr.add_edge(nodes[1], nodes[2], "12");
+
+    // the former last node takes the place of the middle one
+    assert_eq!(gr.swap_remove_node(nodes[2]), Some((2, Some(nodes[4]))));
+    assert_eq!(gr.node_count(), 4);
+    assert_eq!(gr[nodes[2]], 4);
+    assert!(gr.find_edge(nodes[2], nodes[0]).is_some());
+    assert_eq!(gr.edge_count(), 1);
+
+    // removing the last node relocates nothing
+    assert_eq!(gr.swap_remove_node(nodes[3]), Some((3, None)));
+    assert_eq!(gr.swap_remove_node(nodes[3]), None);
+    assert_eq!(gr.swap_remove_node(nodes[0]), Some((0, Some(nodes[2]))));
+    assert_eq!(gr[nodes[0]], 4);
+    assert_eq!(gr.edge_count(), 0);
+
+    gr.clear();
+    assert_eq!(gr.swap_remove_node(nodes[0]), None);
+}
+
+#[test]
+fn edge_endpoints_as_added() {
+    fn check<Ty: EdgeType>() {
+        let mut gr = Graph::<_, _, Ty>::default();
+        let a = gr.add_node("a");
+        let b = gr.add_node("b");
+        let ab = gr.add_edge(a, b, 1);
+        let ba = gr.add_edge(b, a, 2);
+        let bb = gr.add_edge(b, b, 3);
+        assert_eq!(gr.edge_endpoints(ab), Some((a, b)));
+        assert_eq!(gr.edge_endpoints(ba), Some((b, a)));
+        assert_eq!(gr.edge_endpoints(bb), Some((b, b)));
+        assert_eq!(gr.edge_weight(ba), Some(&2));
+        assert_eq!(gr.edge_endpoints(EdgeIndex::new(3)), None);
+        assert_eq!(gr.edge_weight(EdgeIndex::new(3)), None);
+
+        // the last edge takes the place of a removed one, with its endpoints
+        gr.remove_edge(ab);
+        assert_eq!(gr.edge_endpoints(ab), Some((b, b)));
+        assert_eq!(gr.edge_weight(ab), Some(&3));
+        assert_eq!(gr.edge_endpoints(bb), None);
+    }
+    check::<Directed>();
+    check::<Undirected>();
+}
+
+#[test]
+fn scc_labeling() {
+    use petgraph::algo::scc_labeling;
+
+    // two cycles 0 -> 1 -> 2 -> 0 and 3 <-> 4, joined by 2 -> 3, and a lone node 5
+    let mut gr = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 3), (2, 3)]);
+    gr.add_node(());
+    let (labels, count) = scc_labeling(&gr);
+    assert_eq!(count, 3);
+    assert_eq!(labels.len(), gr.node_count());
+    assert!(labels[0] == labels[1] && labels[1] == labels[2]);
+    assert_eq!(labels[3], labels[4]);
+    assert!(labels[0] != labels[3] && labels[5] != labels[0] && labels[5] != labels[3]);
+    // in reverse topological order
+    for edge in gr.raw_edges() {
+        assert!(labels[edge.target().index()] <= labels[edge.source().index()]);
+    }
+    assert!(labels[3] < labels[0]);
+    assert_eq!(scc_labeling(&Graph::<(), ()>::new()), (vec![], 0));
+}
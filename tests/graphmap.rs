@@ -441,3 +441,20 @@ fn test_alternative_hasher() {
     assert!(gr.contains_edge("abc", "def"));
     assert!(!gr.contains_edge("abc", "ghi"));
 }
+
+#[test]
+fn transitive_closure_sparse_nodes() {
+    use petgraph::adj::UnweightedList;
+    use petgraph::algo::tred::transitive_closure;
+    use petgraph::visit::{NodeCount, NodeIndexable};
+
+    // node weights that are far from their compact indices
+    let g = DiGraphMap::<u32, ()>::from_edges(&[(10, 20), (20, 30)]);
+    let tclos: UnweightedList<u32> = transitive_closure(&g, false);
+    let ix = |n| g.to_index(n) as u32;
+    assert_eq!(tclos.node_count(), 3);
+    assert!(tclos.contains_edge(ix(10), ix(30)));
+    assert!(tclos.contains_edge(ix(20), ix(30)));
+    assert!(!tclos.contains_edge(ix(30), ix(10)));
+    assert_eq!(tclos.edge_count(), 3);
+}
@@ -480,6 +480,49 @@ fn iso_large() {
     assert!(petgraph::algo::is_isomorphic(&g0, &g1));
 }
 
+#[test]
+fn iso_path_cycle() {
+    // a path and a cycle on five nodes
+    let path = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+    let mut cycle = path.clone();
+    cycle.add_edge(node_index(4), node_index(0), ());
+    assert!(!is_isomorphic(&path, &cycle));
+
+    // the same counts of nodes and edges, but other degrees:
+    // a cycle of four, and a triangle with a tail
+    let square = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let paw = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+    assert!(!is_isomorphic(&square, &paw));
+
+    // a relabeled copy of the path: 3 - 0 - 4 - 2 - 1
+    let mut relabeled = UnGraph::<u32, ()>::from_edges(&[(3, 0), (0, 4), (4, 2), (2, 1)]);
+    assert!(is_isomorphic(&path, &relabeled));
+
+    // with the node weights, the copy must map the ends of the path to each other
+    let mut path = path;
+    for (i, w) in path.node_weights_mut().enumerate() {
+        *w = i as u32;
+    }
+    for (i, &w) in [1, 4, 3, 0, 2].iter().enumerate() {
+        relabeled[node_index(i)] = w;
+    }
+    assert!(is_isomorphic_matching(
+        &path,
+        &relabeled,
+        |a, b| a == b,
+        |_, _| true
+    ));
+    relabeled[node_index(3)] = 1;
+    relabeled[node_index(0)] = 0;
+    assert!(!is_isomorphic_matching(
+        &path,
+        &relabeled,
+        |a, b| a == b,
+        |_, _| true
+    ));
+    assert!(is_isomorphic(&path, &relabeled));
+}
+
 // isomorphism isn't correct for multigraphs.
 // Keep this testcase to document how
 #[should_panic]
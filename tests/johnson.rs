@@ -0,0 +1,58 @@
+use petgraph::algo::{floyd_warshall, johnson};
+use petgraph::prelude::*;
+
+#[test]
+fn johnson_matches_floyd_warshall() {
+    //   0 --4--> 1 --(-3)--> 2 --2--> 3
+    //   ^                    |
+    //    ---------1----------
+    // and 4, which links to 3 but can't be reached
+    let graph =
+        Graph::<(), i32>::from_edges(&[(0, 1, 4), (1, 2, -3), (2, 0, 1), (2, 3, 2), (4, 3, -5)]);
+    let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res, floyd_warshall(&graph, |edge| *edge.weight()).unwrap());
+
+    let inf = std::i32::MAX;
+    let expected = [
+        [0, 4, 1, 3, inf],
+        [-2, 0, -3, -1, inf],
+        [1, 5, 0, 2, inf],
+        [inf, inf, inf, 0, inf],
+        [inf, inf, inf, -5, 0],
+    ];
+    assert_eq!(res.len(), 25);
+    for i in graph.node_indices() {
+        for j in graph.node_indices() {
+            assert_eq!(res[&(i, j)], expected[i.index()][j.index()], "{:?}", (i, j));
+        }
+    }
+}
+
+#[test]
+fn johnson_undirected() {
+    let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.5), (1, 2, 2.), (0, 2, 4.)]);
+    let res = johnson(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res, floyd_warshall(&graph, |edge| *edge.weight()).unwrap());
+    assert_eq!(res[&(2.into(), 0.into())], 3.5);
+
+    // an undirected edge of negative cost is a negative cycle
+    let graph = UnGraph::<(), i32>::from_edges(&[(0, 1, 1), (1, 2, -1)]);
+    assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+}
+
+#[test]
+fn johnson_negative_cycle() {
+    let mut graph = Graph::<(), i32>::from_edges(&[(0, 1, 1), (1, 2, -3), (2, 0, 1), (3, 0, 1)]);
+    assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+    assert!(floyd_warshall(&graph, |edge| *edge.weight()).is_err());
+
+    // a negative self loop
+    graph.clear_edges();
+    graph.add_edge(3.into(), 3.into(), -1);
+    assert!(johnson(&graph, |edge| *edge.weight()).is_err());
+
+    let res = johnson(&graph, |edge| edge.weight().abs()).unwrap();
+    assert_eq!(res[&(3.into(), 3.into())], 0);
+    let empty = Graph::<(), i32>::new();
+    assert!(johnson(&empty, |edge| *edge.weight()).unwrap().is_empty());
+}
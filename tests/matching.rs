@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::hash::Hash;
 
-use petgraph::algo::{greedy_matching, maximum_matching};
+use petgraph::algo::{greedy_matching, maximum_bipartite_matching, maximum_matching};
 use petgraph::prelude::*;
 
 macro_rules! assert_one_of {
@@ -103,6 +103,34 @@ fn maximum_odd_path() {
     assert_eq!(collect(m.nodes()), set![0, 1, 2, 3]);
 }
 
+#[test]
+fn bipartite_unbalanced() {
+    // 6 - 0 - 3 - 1 - 4 - 2 - 5, with an extra node on the left side
+    // and an isolated edge in another component
+    let g: UnGraph<(), ()> =
+        UnGraph::from_edges(&[(6, 0), (0, 3), (3, 1), (1, 4), (4, 2), (2, 5), (7, 8)]);
+    let m = maximum_bipartite_matching(&g).unwrap();
+    assert_eq!(m.len(), 4);
+    assert_eq!(m.mate(NodeIndex::new(7)), Some(NodeIndex::new(8)));
+    assert!(!m.is_perfect());
+    assert_eq!(collect(m.nodes()).len(), 8);
+    assert!(m.edges().all(|(a, b)| g.contains_edge(a, b)));
+
+    // a star can only match one leaf
+    let g: UnGraph<(), ()> = UnGraph::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+    assert_eq!(maximum_bipartite_matching(&g).unwrap().len(), 1);
+
+    // a directed cycle of even length is bipartite, but not of odd length
+    let mut g: DiGraph<(), ()> = DiGraph::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let m = maximum_bipartite_matching(&g).unwrap();
+    assert!(m.is_perfect());
+    g.add_edge(NodeIndex::new(0), NodeIndex::new(2), ());
+    assert!(maximum_bipartite_matching(&g).is_none());
+
+    let g: UnGraph<(), ()> = UnGraph::default();
+    assert!(maximum_bipartite_matching(&g).unwrap().is_empty());
+}
+
 #[cfg(feature = "stable_graph")]
 #[test]
 fn maximum_in_stable_graph() {
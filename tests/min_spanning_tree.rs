@@ -57,3 +57,159 @@ fn mst() {
     assert!(mst.find_edge(d, b).is_none());
     assert!(mst.find_edge(b, c).is_none());
 }
+
+#[test]
+fn mst_weight_is_minimal() {
+    use petgraph::algo::{connected_components, is_cyclic_undirected};
+    use petgraph::data::FromElements;
+
+    let mut gr = UnGraph::<(), u32>::from_edges(&[
+        (0, 1, 4),
+        (0, 2, 3),
+        (1, 2, 1),
+        (1, 3, 2),
+        (2, 3, 4),
+        (3, 4, 2),
+        (4, 5, 6),
+        (3, 5, 7),
+    ]);
+    // a separate component
+    gr.extend_with_edges(&[(6, 7, 5), (7, 8, 1), (6, 8, 2)]);
+
+    let mst = UnGraph::<(), u32>::from_elements(min_spanning_tree(&gr));
+    let components = connected_components(&gr);
+    assert_eq!(components, 2);
+    assert_eq!(mst.edge_count(), gr.node_count() - components);
+    let total: u32 = mst.edge_weights().sum();
+    assert_eq!(total, 3 + 1 + 2 + 2 + 6 + 1 + 2);
+
+    // compare with the best spanning forest found by brute force
+    let edges = gr.raw_edges();
+    let mut best = None;
+    for subset in 0u32..1 << edges.len() {
+        let mut forest = UnGraph::<(), u32>::with_capacity(gr.node_count(), edges.len());
+        for _ in gr.node_indices() {
+            forest.add_node(());
+        }
+        for (i, e) in edges.iter().enumerate() {
+            if subset & (1 << i) != 0 {
+                forest.add_edge(e.source(), e.target(), e.weight);
+            }
+        }
+        if forest.edge_count() == gr.node_count() - components && !is_cyclic_undirected(&forest) {
+            let weight: u32 = forest.edge_weights().sum();
+            best = Some(best.map_or(weight, |b: u32| b.min(weight)));
+        }
+    }
+    assert_eq!(best, Some(total));
+}
+
+#[test]
+fn prim_matches_kruskal() {
+    use petgraph::algo::prim_mst;
+    use petgraph::data::FromElements;
+
+    // a directed graph, treated as undirected
+    let mut gr = Graph::<(), u32>::from_edges(&[
+        (0, 1, 7),
+        (0, 3, 5),
+        (3, 1, 9),
+        (1, 2, 8),
+        (1, 4, 7),
+        (2, 4, 5),
+        (3, 4, 15),
+        (3, 5, 6),
+        (5, 4, 8),
+        (5, 6, 11),
+        (4, 6, 9),
+    ]);
+    let kruskal = UnGraph::<(), u32>::from_elements(min_spanning_tree(&gr));
+    let kruskal_weight: u32 = kruskal.edge_weights().sum();
+
+    for start in gr.node_indices() {
+        let tree = prim_mst(&gr, start);
+        assert_eq!(tree.len(), gr.node_count() - 1);
+        assert_eq!(tree.iter().map(|&e| gr[e]).sum::<u32>(), kruskal_weight);
+    }
+
+    // only the component of the start is spanned
+    let h = gr.add_node(());
+    let i = gr.add_node(());
+    gr.add_edge(i, h, 1);
+    assert_eq!(prim_mst(&gr, h), vec![gr.find_edge(i, h).unwrap()]);
+    let j = gr.add_node(());
+    assert_eq!(prim_mst(&gr, j), vec![]);
+    let tree = prim_mst(&gr, petgraph::graph::node_index(6));
+    assert_eq!(tree.iter().map(|&e| gr[e]).sum::<u32>(), kruskal_weight);
+}
+
+#[test]
+fn spanning_forest_is_acyclic() {
+    use petgraph::algo::spanning_forest;
+    use petgraph::unionfind::UnionFind;
+
+    // a connected graph with cycles, a self loop and parallel edges
+    let mut gr = Graph::<(), ()>::from_edges(&[
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 3),
+        (3, 4),
+        (4, 3),
+        (4, 5),
+        (5, 1),
+    ]);
+    let forest = spanning_forest(&gr);
+    assert_eq!(forest.len(), gr.node_count() - 1);
+    let mut sets = UnionFind::new(gr.node_count());
+    for &e in &forest {
+        let (a, b) = gr.edge_endpoints(e).unwrap();
+        assert!(sets.union(a.index(), b.index()), "cycle through {:?}", e);
+    }
+
+    // one tree for each component, and none for an isolated node
+    gr.extend_with_edges(&[(6, 7), (8, 7)]);
+    gr.add_node(());
+    assert_eq!(spanning_forest(&gr).len(), gr.node_count() - 3);
+    assert!(spanning_forest(&UnGraph::<(), ()>::new_undirected()).is_empty());
+}
+
+#[test]
+fn min_spanning_arborescence_nested_cycles() {
+    use petgraph::algo::min_spanning_arborescence;
+    use petgraph::graph::{edge_index as e, node_index as n};
+
+    let mut gr = Graph::<(), u32>::from_edges(&[
+        (0, 1, 5),
+        (0, 2, 10),
+        (1, 2, 3),
+        (2, 1, 2),
+        (2, 3, 4),
+        (3, 4, 1),
+        (4, 3, 1),
+        (4, 2, 1),
+        (1, 4, 8),
+        (0, 3, 20),
+    ]);
+    // The cheapest incoming edges close the cycle 3 - 4, then 2 - {3, 4},
+    // then 1 - {2, 3, 4}, which is entered from the root through 1.
+    let tree = min_spanning_arborescence(&gr, n(0)).unwrap();
+    assert_eq!(tree, vec![e(0), e(2), e(4), e(5)]);
+    assert_eq!(tree.iter().map(|&e| gr[e]).sum::<u32>(), 13);
+
+    // self loops and edges into the root are never used
+    gr.add_edge(n(2), n(2), 0);
+    gr.add_edge(n(1), n(0), 0);
+    assert_eq!(min_spanning_arborescence(&gr, n(0)).unwrap(), tree);
+
+    // a node only leading into the graph is unreachable
+    gr.extend_with_edges(&[(5, 3, 0)]);
+    assert_eq!(min_spanning_arborescence(&gr, n(0)), None);
+    // a cycle only entered from itself is unreachable
+    let cycle = Graph::<(), u32>::from_edges(&[(1, 2, 1), (2, 1, 1)]);
+    assert_eq!(min_spanning_arborescence(&cycle, n(0)), None);
+
+    let single = Graph::<(), u32>::from_edges(&[(0, 0, 1)]);
+    assert_eq!(min_spanning_arborescence(&single, n(0)), Some(vec![]));
+}
@@ -38,3 +38,118 @@ fn test_complement() {
         }
     }
 }
+
+#[test]
+fn test_union_intersection() {
+    use petgraph::operator::{intersection, union};
+
+    let mut a = Graph::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (2, 0, 1)]);
+    let b = Graph::<u32, u32>::from_edges(&[(1, 2, 2), (2, 1, 2), (2, 3, 2), (3, 4, 2)]);
+    for (nx, w) in a.node_weights_mut().enumerate() {
+        *w = nx as u32;
+    }
+
+    let u = union(&a, &b);
+    assert_eq!(u.node_count(), 5);
+    assert_eq!(u.edge_count(), 6);
+    assert_eq!(u[NodeIndex::new(1)], 1);
+    assert_eq!(u[u.find_edge(1.into(), 2.into()).unwrap()], 1);
+    assert_eq!(u[u.find_edge(2.into(), 1.into()).unwrap()], 2);
+
+    let i = intersection(&a, &b);
+    assert_eq!(i.node_count(), 3);
+    assert_eq!(i.edge_count(), 1);
+    assert_eq!(i[NodeIndex::new(1)], 1);
+    assert!(i.contains_edge(1.into(), 2.into()));
+
+    // the intersection of the union with an operand has the edges of the operand
+    let ui = intersection(&union(&a, &b), &b);
+    assert_eq!(ui.node_count(), b.node_count());
+    assert_eq!(ui.edge_count(), b.edge_count());
+    for edge in b.raw_edges() {
+        assert!(ui.contains_edge(edge.source(), edge.target()));
+    }
+    // and both are idempotent
+    for g in &[union(&a, &a), intersection(&a, &a)] {
+        assert_eq!(g.node_count(), a.node_count());
+        assert_eq!(g.edge_count(), a.edge_count());
+    }
+
+    // undirected edges are the same in either direction
+    a.add_edge(1.into(), 2.into(), 3);
+    let a = a.into_undirected();
+    let b = b.into_undirected();
+    assert_eq!(union(&a, &b).edge_count(), 6);
+    let i = intersection(&a, &b);
+    assert_eq!(i.edge_count(), 2);
+    assert!(i.edge_weights().all(|&w| w != 2));
+}
+
+#[test]
+fn test_line_graph() {
+    use petgraph::graph::edge_index;
+    use petgraph::operator::line_graph;
+
+    // the line graph of the path P3 is the path P2
+    let p3 = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    let l = line_graph(&p3);
+    assert_eq!(l.node_count(), 2);
+    assert_eq!(l.edge_count(), 1);
+    assert!(l.contains_edge(0.into(), 1.into()));
+    assert_eq!(
+        l.node_weights().cloned().collect::<Vec<_>>(),
+        vec![edge_index(0), edge_index(1)]
+    );
+
+    // a star becomes a triangle, parallel edges are adjacent once, a self loop is not adjacent
+    // to itself
+    let mut star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (3, 0)]);
+    assert_eq!(line_graph(&star).edge_count(), 3);
+    star.add_edge(1.into(), 0.into(), ());
+    star.add_edge(1.into(), 1.into(), ());
+    let l = line_graph(&star);
+    assert_eq!(l.edges_connecting(0.into(), 3.into()).count(), 1);
+    assert!(!l.contains_edge(4.into(), 4.into()));
+    assert!(l.contains_edge(4.into(), 0.into()));
+    assert!(!l.contains_edge(4.into(), 1.into()));
+    assert_eq!(l.edge_count(), 6 + 2);
+
+    // directed edges are adjacent head to tail
+    let mut dg = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3)]);
+    let l = line_graph(&dg);
+    assert_eq!(l.edge_count(), 4);
+    assert!(l.contains_edge(0.into(), 1.into()));
+    assert!(!l.contains_edge(1.into(), 0.into()));
+    assert!(l.contains_edge(2.into(), 0.into()));
+    assert!(l.contains_edge(2.into(), 3.into()));
+    dg.add_edge(3.into(), 3.into(), ());
+    let l = line_graph(&dg);
+    assert!(l.contains_edge(4.into(), 4.into()));
+    assert!(l.contains_edge(3.into(), 4.into()));
+    assert!(!l.contains_edge(4.into(), 3.into()));
+}
+
+#[test]
+fn test_complement_complete_undirected() {
+    // the complement of a complete graph has no edges
+    let mut complete =
+        UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    let mut output = UnGraph::new_undirected();
+    complement(&complete, &mut output, ());
+    assert_eq!(output.node_count(), 4);
+    assert_eq!(output.edge_count(), 0);
+
+    // each missing undirected edge is added once
+    complete.remove_edge(complete.find_edge(2.into(), 1.into()).unwrap());
+    complete.add_node(());
+    let mut output = UnGraph::new_undirected();
+    complement(&complete, &mut output, ());
+    assert_eq!(output.edge_count(), 1 + 4);
+    assert!(output.contains_edge(1.into(), 2.into()));
+    assert_eq!(output.edges_connecting(2.into(), 1.into()).count(), 1);
+
+    let complete = Graph::<(), ()>::from_edges(&[(0, 1), (1, 0), (0, 2), (2, 0), (1, 2), (2, 1)]);
+    let mut output = Graph::new();
+    complement(&complete, &mut output, ());
+    assert_eq!(output.edge_count(), 0);
+}
@@ -1,4 +1,7 @@
-use petgraph::{algo::page_rank, Graph};
+use petgraph::{
+    algo::{page_rank, page_rank_with_tolerance},
+    Graph,
+};
 
 #[cfg(feature = "rayon")]
 use petgraph::algo::page_rank::parallel_page_rank;
@@ -81,3 +84,61 @@ fn test_par_page_rank() {
             || computed.is_nan()
             || expected.is_nan()));
 }
+
+#[test]
+fn test_page_rank_with_tolerance() {
+    // a dangling node shares its rank with every node:
+    // r0 = 0.25 + 0.25 r1 and r1 = 0.25 + 0.5 r0 + 0.25 r1
+    let graph = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    let ranks = page_rank_with_tolerance(&graph, 0.5_f64, 1e-12, 1000);
+    assert!((ranks[0] - 0.4).abs() < 1e-9);
+    assert!((ranks[1] - 0.6).abs() < 1e-9);
+
+    let graph = graph_example();
+    let ranks = page_rank_with_tolerance(&graph, 0.85_f32, 1e-6, 100);
+    assert_eq!(ranks.len(), graph.node_count());
+    assert!((ranks.iter().sum::<f32>() - 1.).abs() < 1e-5);
+    // B and C rank first, as with `page_rank`
+    assert!(ranks[1] > ranks[2]);
+    assert!(ranks.iter().skip(2).all(|&r| r <= ranks[2]));
+
+    // a single iteration from the uniform ranks
+    let ranks = page_rank_with_tolerance(&graph, 0.85_f32, 0., 1);
+    assert!((ranks.iter().sum::<f32>() - 1.).abs() < 1e-5);
+
+    let empty = Graph::<(), ()>::new();
+    assert_eq!(
+        page_rank_with_tolerance(&empty, 0.85_f64, 1e-6, 100),
+        vec![]
+    );
+}
+
+#[test]
+#[should_panic(expected = "strictly between 0 and 1")]
+fn test_page_rank_with_tolerance_damping() {
+    let graph = graph_example();
+    page_rank_with_tolerance(&graph, 1_f32, 1e-6, 100);
+}
+
+#[test]
+#[should_panic(expected = "strictly between 0 and 1")]
+fn test_page_rank_with_tolerance_damping_empty() {
+    let empty = Graph::<(), ()>::new();
+    page_rank_with_tolerance(&empty, 2_f64, 1e-6, 100);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn test_page_rank_with_tolerance_stable_graph() {
+    use petgraph::graph::node_index;
+    use petgraph::prelude::StableGraph;
+
+    // 0 and 2 link to each other, and the node 1 between them is removed
+    let mut graph = StableGraph::<(), ()>::from_edges(&[(0, 2), (2, 0), (0, 1)]);
+    graph.remove_node(node_index(1));
+    let ranks = page_rank_with_tolerance(&graph, 0.85_f64, 1e-12, 1000);
+    assert_eq!(ranks.len(), 3);
+    assert!((ranks[0] - 0.5).abs() < 1e-9);
+    assert_eq!(ranks[1], 0.);
+    assert!((ranks[2] - 0.5).abs() < 1e-9);
+}
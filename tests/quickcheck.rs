@@ -22,11 +22,16 @@ use itertools::cloned;
 use quickcheck::{Arbitrary, Gen};
 use rand::Rng;
 
+use petgraph::algo::tred::transitive_closure;
 use petgraph::algo::{
-    bellman_ford, condensation, dijkstra, find_negative_cycle, floyd_warshall, ford_fulkerson,
-    greedy_feedback_arc_set, greedy_matching, is_cyclic_directed, is_cyclic_undirected,
-    is_isomorphic, is_isomorphic_matching, k_shortest_path, kosaraju_scc, maximum_matching,
-    min_spanning_tree, page_rank, tarjan_scc, toposort, Matching,
+    articulation_points, bellman_ford, betweenness_centrality, bridges, condensation,
+    connected_components, cycle_basis, dijkstra, eulerian_path, find_negative_cycle,
+    floyd_warshall, ford_fulkerson, greedy_coloring, greedy_feedback_arc_set, greedy_matching,
+    has_path_connecting, is_cyclic_directed, is_cyclic_undirected, is_isomorphic,
+    is_isomorphic_matching, johnson, k_core, k_shortest_path, kosaraju_scc,
+    maximum_bipartite_matching, maximum_matching, min_cut, min_spanning_arborescence,
+    min_spanning_tree, page_rank, prim_mst, scc_labeling, spanning_forest, stoer_wagner_min_cut,
+    tarjan_scc, toposort, union_find, Matching,
 };
 use petgraph::data::FromElements;
 use petgraph::dot::{Config, Dot};
@@ -34,6 +39,7 @@ use petgraph::graph::{edge_index, node_index, IndexType};
 use petgraph::graphmap::NodeTrait;
 use petgraph::operator::complement;
 use petgraph::prelude::*;
+use petgraph::unionfind::UnionFind;
 use petgraph::visit::{
     EdgeFiltered, EdgeIndexable, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
     IntoNodeReferences, NodeCount, NodeIndexable, Reversed, Topo, VisitMap, Visitable,
@@ -52,6 +58,371 @@ where
 
 use std::fmt;
 
+quickcheck! {
+    // Prim's trees of all components weigh as much as Kruskal's forest
+    fn prim_mst_weight(g: Small<Graph<(), u32>>) -> bool {
+        let kruskal = mst_graph(&g);
+        let kruskal_weight: u64 = kruskal.edge_weights().map(|&w| u64::from(w)).sum();
+
+        let mut spanned = g.visit_map();
+        let mut prim_weight = 0;
+        let mut prim_edges = 0;
+        for start in g.node_indices() {
+            if spanned.is_visited(&start) {
+                continue;
+            }
+            let tree = prim_mst(&*g, start);
+            spanned.visit(start);
+            for &e in &tree {
+                let (a, b) = g.edge_endpoints(e).unwrap();
+                spanned.visit(a);
+                spanned.visit(b);
+                prim_weight += u64::from(g[e]);
+            }
+            prim_edges += tree.len();
+        }
+        prim_edges == kruskal.edge_count() && prim_weight == kruskal_weight
+    }
+}
+
+quickcheck! {
+    // a spanning forest has an edge less than nodes in each component, and no cycle
+    fn spanning_forest_acyclic(g: Small<Graph<(), ()>>) -> bool {
+        let forest = spanning_forest(&*g);
+        let mut sets = UnionFind::new(g.node_count());
+        forest.len() == g.node_count() - connected_components(&*g)
+            && forest.iter().all(|&e| {
+                let (a, b) = g.edge_endpoints(e).unwrap();
+                sets.union(a.index(), b.index())
+            })
+    }
+
+    // there are |E| - |V| + c fundamental cycles, each of distinct nodes joined by edges
+    fn cycle_basis_cycles(g: Small<Graph<(), ()>>) -> bool {
+        let cycles = cycle_basis(&*g);
+        let edges_between = |a, b| {
+            g.edges_connecting(a, b).count() + if a == b { 0 } else { g.edges_connecting(b, a).count() }
+        };
+        cycles.len() + g.node_count() == g.edge_count() + connected_components(&*g)
+            && cycles.iter().all(|cycle| {
+                let mut nodes = cycle.clone();
+                nodes.sort();
+                nodes.dedup();
+                let k = cycle.len();
+                nodes.len() == k
+                    && match k {
+                        1 | 2 => edges_between(cycle[0], cycle[k - 1]) >= k,
+                        _ => (0..k).all(|i| edges_between(cycle[i], cycle[(i + 1) % k]) > 0),
+                    }
+            })
+    }
+
+    // the arborescence spans the nodes reachable from the root, and weighs no more than
+    // any other, when there are few enough to try them all
+    fn min_spanning_arborescence_brute_force(g: Small<Graph<(), u8>>) -> bool {
+        if g.node_count() == 0 {
+            return true;
+        }
+        use petgraph::visit::Walker;
+        let root = node_index(0);
+        let reachable = Dfs::new(&*g, root).iter(&*g).count() == g.node_count();
+        let tree = match min_spanning_arborescence(&g, root) {
+            None => return !reachable,
+            Some(tree) => tree,
+        };
+        let weight = |edges: &[EdgeIndex]| edges.iter().map(|&e| u32::from(g[e])).sum::<u32>();
+        reachable
+            && is_arborescence(&g, root, &tree)
+            && brute_force_arborescence(&g, root).map_or(true, |w| w == weight(&tree))
+    }
+}
+
+quickcheck! {
+    // nodes have the same label exactly when they reach each other, and edges never go
+    // to a higher label
+    fn scc_labeling_reachability(g: Small<Graph<(), ()>>) -> bool {
+        use petgraph::visit::Walker;
+        let (labels, count) = scc_labeling(&*g);
+        let label = |x: NodeIndex| labels[x.index()];
+        let reach: Vec<HashSet<_>> = g
+            .node_indices()
+            .map(|a| Dfs::new(&*g, a).iter(&*g).collect())
+            .collect();
+        labels.iter().all(|&l| l < count)
+            && labels.iter().collect::<HashSet<_>>().len() == count
+            && g.raw_edges().iter().all(|e| label(e.target()) <= label(e.source()))
+            && g.node_indices().all(|a| {
+                g.node_indices().all(|b| {
+                    let strong = reach[a.index()].contains(&b) && reach[b.index()].contains(&a);
+                    strong == (label(a) == label(b))
+                })
+            })
+    }
+}
+
+quickcheck! {
+    // the global minimum cut weighs as much as the lightest of all bipartitions
+    fn stoer_wagner_brute_force(g: Small<UnGraph<(), u8>>) -> bool {
+        let n = g.node_count();
+        if n > 10 {
+            return true;
+        }
+        let cut_weight = |side: &dyn Fn(NodeIndex) -> bool| {
+            g.edge_references()
+                .filter(|e| side(e.source()) != side(e.target()))
+                .map(|e| u32::from(*e.weight()))
+                .sum::<u32>()
+        };
+        let result = stoer_wagner_min_cut(&*g, |e| u32::from(*e.weight()));
+        if n < 2 {
+            return result.is_none();
+        }
+        let (weight, side) = result.unwrap();
+        // the node n - 1 is on the other side of each bipartition
+        let best = (1..1u32 << (n - 1))
+            .map(|set| cut_weight(&|x| set & (1 << x.index()) != 0))
+            .min()
+            .unwrap();
+        !side.is_empty()
+            && side.len() < n
+            && weight == best
+            && cut_weight(&|x| side.contains(&x)) == weight
+    }
+}
+
+// Every node but the root has one incoming edge, and leads back to the root
+fn is_arborescence<N, E>(g: &DiGraph<N, E>, root: NodeIndex, tree: &[EdgeIndex]) -> bool {
+    let mut parent = vec![None; g.node_count()];
+    for &e in tree {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        if b == root || parent[b.index()].replace(a).is_some() {
+            return false;
+        }
+    }
+    g.node_indices().all(|mut x| {
+        for _ in 0..g.node_count() {
+            if x == root {
+                return true;
+            }
+            match parent[x.index()] {
+                Some(p) => x = p,
+                None => return false,
+            }
+        }
+        false
+    })
+}
+
+// The least weight of an arborescence, trying every choice of incoming edges,
+// or `None` if there are too many
+fn brute_force_arborescence(g: &DiGraph<(), u8>, root: NodeIndex) -> Option<u32> {
+    let incoming: Vec<Vec<EdgeIndex>> = g
+        .node_indices()
+        .filter(|&x| x != root)
+        .map(|x| {
+            g.edges_directed(x, Incoming)
+                .filter(|e| e.source() != x)
+                .map(|e| e.id())
+                .collect()
+        })
+        .collect();
+    let choices = incoming
+        .iter()
+        .try_fold(1usize, |n, edges| n.checked_mul(edges.len()))?;
+    if choices > 4096 {
+        return None;
+    }
+    (0..choices)
+        .filter_map(|mut choice| {
+            let tree: Vec<_> = incoming
+                .iter()
+                .map(|edges| {
+                    let e = edges[choice % edges.len()];
+                    choice /= edges.len();
+                    e
+                })
+                .collect();
+            if is_arborescence(g, root, &tree) {
+                Some(tree.iter().map(|&e| u32::from(g[e])).sum())
+            } else {
+                None
+            }
+        })
+        .min()
+}
+
+quickcheck! {
+    // articulation points are the nodes whose removal adds a component
+    fn articulation_points_brute_force(g: Small<UnGraph<(), ()>>) -> bool {
+        let points = articulation_points(&*g);
+        let components = connected_components(&*g);
+        g.node_indices().all(|nx| {
+            let mut removed = g.0.clone();
+            removed.remove_node(nx);
+            points.contains(&nx) == (connected_components(&removed) > components)
+        })
+    }
+}
+
+quickcheck! {
+    // summed over all the nodes, centrality counts the inner nodes of the shortest paths
+    fn betweenness_centrality_sum(g: Small<Graph<(), ()>>) -> bool {
+        let total: f64 = betweenness_centrality(&*g, false).iter().sum();
+        let mut expected = 0;
+        for s in g.node_indices() {
+            let mut bfs = Bfs::new(&*g, s);
+            let mut distance = vec![0; g.node_count()];
+            while let Some(v) = bfs.next(&*g) {
+                for w in g.neighbors(v) {
+                    if w != s && distance[w.index()] == 0 {
+                        distance[w.index()] = distance[v.index()] + 1;
+                    }
+                }
+                if v != s {
+                    expected += distance[v.index()] - 1;
+                }
+            }
+        }
+        (total - expected as f64).abs() < 1e-6
+    }
+}
+
+quickcheck! {
+    // peeling nodes of degree below k, one k at a time, leaves the k-core
+    fn k_core_naive(g: Small<UnGraph<(), ()>>) -> bool {
+        let core = k_core(&*g);
+        let mut naive = vec![0; g.node_count()];
+        let mut removed = vec![false; g.node_count()];
+        let mut k = 0;
+        while removed.iter().any(|&r| !r) {
+            k += 1;
+            loop {
+                let low = g.node_indices().find(|&u| {
+                    !removed[u.index()]
+                        && g.edges(u)
+                            .filter(|e| e.source() != e.target())
+                            .filter(|e| !removed[e.source().index()] && !removed[e.target().index()])
+                            .count()
+                            < k
+                });
+                match low {
+                    Some(u) => {
+                        removed[u.index()] = true;
+                        naive[u.index()] = k - 1;
+                    }
+                    None => break,
+                }
+            }
+        }
+        core == naive
+    }
+}
+
+quickcheck! {
+    // the coloring is proper apart from self loops, with at most one color more than the
+    // maximum degree
+    fn greedy_coloring_proper(g: Small<Graph<(), ()>>) -> bool {
+        let (colors, count) = greedy_coloring(&*g);
+        let max_degree = g
+            .node_indices()
+            .map(|u| {
+                g.edges_directed(u, Outgoing)
+                    .chain(g.edges_directed(u, Incoming))
+                    .filter(|e| e.source() != e.target())
+                    .count()
+            })
+            .max()
+            .unwrap_or(0);
+        colors.len() == g.node_count()
+            && colors.iter().all(|&c| c < count)
+            && (g.node_count() == 0 || count <= max_degree + 1)
+            && (0..count).all(|c| colors.contains(&c))
+            && g.edge_references().all(|e| {
+                e.source() == e.target() || colors[e.source().index()] != colors[e.target().index()]
+            })
+    }
+}
+
+// An Eulerian path exists iff the edges are connected and the degrees allow it,
+// and then it is a walk through every edge once
+fn eulerian_path_check<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> bool {
+    let path = eulerian_path(g);
+    let isolated = g
+        .node_indices()
+        .filter(|&u| g.neighbors_undirected(u).next().is_none())
+        .count();
+    let connected = g.edge_count() == 0 || connected_components(g) - isolated == 1;
+    // the degree of each node, or its outgoing minus incoming edges
+    let mut degree = vec![0isize; g.node_count()];
+    for e in g.edge_references() {
+        degree[e.source().index()] += 1;
+        degree[e.target().index()] += if g.is_directed() { -1 } else { 1 };
+    }
+    let unbalanced = degree.iter().filter(|&&d| d % 2 != 0).count();
+    let balanced = !g.is_directed() || degree.iter().all(|&d| d.abs() <= 1);
+    let exists = connected && balanced && unbalanced <= 2;
+    let path = match path {
+        None => return !exists,
+        Some(path) => path,
+    };
+    if !exists || path.len() != g.edge_count() {
+        return false;
+    }
+    // The nodes where the walk so far can end
+    let mut at: Option<Vec<NodeIndex>> = None;
+    let mut used = vec![false; g.edge_count()];
+    for e in path {
+        if used[e.index()] {
+            return false;
+        }
+        used[e.index()] = true;
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        let from = at.unwrap_or_else(|| vec![a, b]);
+        let mut to = Vec::new();
+        for u in from {
+            if u == a {
+                to.push(b);
+            }
+            if u == b && !g.is_directed() {
+                to.push(a);
+            }
+        }
+        if to.is_empty() {
+            return false;
+        }
+        to.sort();
+        to.dedup();
+        at = Some(to);
+    }
+    true
+}
+
+quickcheck! {
+    fn eulerian_path_directed(g: Small<Graph<(), ()>>) -> bool {
+        eulerian_path_check(&g)
+    }
+
+    fn eulerian_path_undirected(g: Small<UnGraph<(), ()>>) -> bool {
+        eulerian_path_check(&g)
+    }
+}
+
+quickcheck! {
+    // bridges are the edges whose removal adds a component
+    fn bridges_brute_force(g: Small<Graph<(), ()>>) -> bool {
+        // dense graphs rarely have bridges and are slow to check edge by edge
+        if g.edge_count() > 3 * g.node_count() {
+            return true;
+        }
+        let found = bridges(&*g);
+        let components = connected_components(&*g);
+        g.edge_indices().all(|ex| {
+            let removed = EdgeFiltered::from_fn(&*g, |e| e.id() != ex);
+            found.contains(&ex) == (connected_components(&removed) > components)
+        })
+    }
+}
+
 quickcheck! {
     fn mst_directed(g: Small<Graph<(), u32>>) -> bool {
         // filter out isolated nodes
@@ -138,6 +509,27 @@ fn reverse_directed() {
     quickcheck::quickcheck(prop as fn(Graph<_, _, Directed>) -> bool);
 }
 
+quickcheck! {
+    fn reverse_twice(g: Graph<u32, u32>) -> bool {
+        let mut h = g.clone();
+        h.reverse();
+        for (e, f) in g.raw_edges().iter().zip(h.raw_edges()) {
+            assert_eq!((e.source(), e.target(), e.weight), (f.target(), f.source(), f.weight));
+        }
+        for i in g.node_indices() {
+            assert!(g.neighbors_directed(i, Outgoing).eq(h.neighbors_directed(i, Incoming)));
+        }
+
+        h.reverse();
+        assert_eq!(g.node_weights().collect::<Vec<_>>(), h.node_weights().collect::<Vec<_>>());
+        for i in g.node_indices() {
+            assert!(g.edges(i).map(|e| (e.id(), e.target())).eq(h.edges(i).map(|e| (e.id(), e.target()))));
+            assert!(g.neighbors_directed(i, Incoming).eq(h.neighbors_directed(i, Incoming)));
+        }
+        true
+    }
+}
+
 #[test]
 fn graph_retain_nodes() {
     fn prop<Ty: EdgeType>(mut g: Graph<i32, i32, Ty>) -> bool {
@@ -505,6 +897,51 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // the same partition with vacant node indices
+    fn stable_graph_sccs(g: StableGraph<(), ()>) -> bool {
+        let mut sccs = kosaraju_scc(&g);
+        let mut tsccs = tarjan_scc(&g);
+        sort_sccs(&mut sccs);
+        sort_sccs(&mut tsccs);
+        sccs == tsccs && sccs.iter().map(|scc| scc.len()).sum::<usize>() == g.node_count()
+    }
+}
+
+quickcheck! {
+    // nodes are in the same set iff a search ignoring directions reaches one from the other
+    fn union_find_bfs(g: Small<Graph<(), ()>>) -> bool {
+        let sets = union_find(&*g);
+        let un = g.0.clone().into_undirected();
+        g.node_indices().all(|a| {
+            let mut reached = vec![false; g.node_count()];
+            let mut bfs = Bfs::new(&un, a);
+            while let Some(b) = bfs.next(&un) {
+                reached[b.index()] = true;
+            }
+            g.node_indices()
+                .all(|b| sets.equiv(a.index(), b.index()) == reached[b.index()])
+        })
+    }
+}
+
+quickcheck! {
+    // the closure has an edge wherever there is a path, of length zero only if reflexive
+    fn transitive_closure_paths(g: Small<StableGraph<(), ()>>, reflexive: bool) -> bool {
+        let tclos = transitive_closure(&*g, reflexive);
+        g.node_indices().all(|a| {
+            g.node_indices().all(|b| {
+                let path = if a == b {
+                    reflexive || g.neighbors(a).any(|c| has_path_connecting(&*g, c, a, None))
+                } else {
+                    has_path_connecting(&*g, a, b, None)
+                };
+                tclos.contains_edge(a, b) == path
+            })
+        })
+    }
+}
+
 quickcheck! {
     fn kosaraju_scc_is_topo_sort(g: Graph<(), ()>) -> bool {
         let tsccs = kosaraju_scc(&g);
@@ -692,6 +1129,22 @@ fn full_topo() {
     quickcheck::quickcheck(prop as fn(_) -> bool);
 }
 
+quickcheck! {
+    // toposort fails exactly on cyclic graphs, with a node that is on a cycle
+    fn toposort_cycle(gr: Graph<(), ()>) -> bool {
+        match toposort(&gr, None) {
+            Ok(order) => !is_cyclic_directed(&gr) && is_topo_order(&gr, &order),
+            Err(cycle) => {
+                let nx = cycle.node_id();
+                is_cyclic_directed(&gr)
+                    && gr
+                        .neighbors(nx)
+                        .any(|succ| petgraph::algo::has_path_connecting(&gr, succ, nx, None))
+            }
+        }
+    }
+}
+
 #[test]
 fn full_topo_generic() {
     fn prop_generic(DAG(mut gr): DAG<usize>) -> bool {
@@ -835,6 +1288,14 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // checks johnson against floyd_warshall, also with negative weights
+    fn johnson_floyd_warshall(g: Graph<(), i8>) -> bool {
+        let cost = |e: petgraph::graph::EdgeReference<i8>| i64::from(*e.weight());
+        johnson(&g, cost) == floyd_warshall(&g, cost)
+    }
+}
+
 quickcheck! {
     // checks that the complement of the complement is the same as the input if the input does not contain self-loops
     fn complement_(g: Graph<u32, u32>, _node: usize) -> bool {
@@ -917,6 +1378,35 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // Bfs visits the nodes reachable from the start in order of distance, and
+    // Dfs visits the same nodes.
+    fn bfs_dfs_visit(gr: Graph<(), ()>, node: usize) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let start = node_index(node % gr.node_count());
+        let distances = dijkstra(&gr, start, None, |_| 1);
+
+        let mut bfs = Bfs::new(&gr, start);
+        let mut bfs_order = Vec::new();
+        while let Some(nx) = bfs.next(&gr) {
+            bfs_order.push(nx);
+        }
+        let mut dfs = Dfs::new(&gr, start);
+        let mut dfs_order = Vec::new();
+        while let Some(nx) = dfs.next(&gr) {
+            dfs_order.push(nx);
+        }
+
+        bfs_order.len() == distances.len()
+            && set(bfs_order.iter().cloned()) == set(distances.keys().cloned())
+            && bfs_order.windows(2).all(|w| distances[&w[0]] <= distances[&w[1]])
+            && dfs_order.len() == distances.len()
+            && set(dfs_order) == set(bfs_order)
+    }
+}
+
 quickcheck! {
     fn test_bellman_ford(gr: Graph<(), f32>) -> bool {
         let mut gr = gr;
@@ -950,6 +1440,27 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // the path found is a cycle of negative weight, and there is one iff bellman_ford fails
+    fn find_negative_cycle_is_cycle(g: Small<Graph<(), i8>>) -> bool {
+        let gr = g.map(|_, _| (), |_, &w| f64::from(w));
+        gr.node_indices().take(4).all(|start| match find_negative_cycle(&gr, start) {
+            None => bellman_ford(&gr, start).is_ok(),
+            Some(cycle) => {
+                let mut weight = 0.;
+                for (i, &a) in cycle.iter().enumerate() {
+                    let b = cycle[(i + 1) % cycle.len()];
+                    match gr.edges_connecting(a, b).map(|e| *e.weight()).reduce(f64::min) {
+                        Some(w) => weight += w,
+                        None => return false,
+                    }
+                }
+                weight < 0. && bellman_ford(&gr, start).is_err()
+            }
+        })
+    }
+}
+
 quickcheck! {
     fn test_bellman_ford_undir(gr: Graph<(), f32, Undirected>) -> bool {
         let mut gr = gr;
@@ -975,6 +1486,40 @@ defmac!(edges_eq ref a, ref b =>
             a.edge_references().map(|e| (e.source(), e.target())),
             b.edge_references().map(|e| (e.source(), e.target()))));
 
+quickcheck! {
+    // contracting an edge keeps every other edge, with its endpoints merged
+    fn contract_edge_degrees(g: Graph<u32, u32>, e: usize) -> bool {
+        if g.edge_count() == 0 {
+            return true;
+        }
+        let e = edge_index(e % g.edge_count());
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        let mut h = g.clone();
+        let merged = h.contract_edge(e, |x, y| x.wrapping_add(y)).unwrap();
+        assert_eq!(h.edge_count(), g.edge_count() - 1);
+        if a == b {
+            assert_eq!(merged, a);
+            assert_eq!(h.node_count(), g.node_count());
+            assert_eq!(h.degree(merged), g.degree(a) - 2);
+        } else {
+            assert_eq!(h.node_count(), g.node_count() - 1);
+            assert_eq!(h.degree(merged), g.degree(a) + g.degree(b) - 2);
+            assert_eq!(h[merged], g[a].wrapping_add(g[b]));
+        }
+        // the other nodes keep their weights
+        let mut rest = g.clone();
+        rest.remove_node(a.max(b));
+        if a != b {
+            rest.remove_node(a.min(b));
+        }
+        let mut expected: Vec<_> = rest.node_weights().cloned().collect();
+        let mut found: Vec<_> = h.node_indices().filter(|&i| i != merged).map(|i| h[i]).collect();
+        expected.sort();
+        found.sort();
+        expected == found
+    }
+}
+
 quickcheck! {
     fn test_di_from(gr1: DiGraph<i32, i32>) -> () {
         let sgr = StableGraph::from(gr1.clone());
@@ -1027,6 +1572,26 @@ quickcheck! {
         );
     }
 
+    fn graph_map_id(gr1: DiGraph<usize, usize>) -> () {
+        let gr2 = gr1.map(|_, &nw| nw, |_, &ew| ew);
+        assert!(nodes_eq!(&gr1, &gr2));
+        assert!(edgew_eq!(&gr1, &gr2));
+        for i in gr1.node_indices() {
+            assert!(gr1.neighbors(i).eq(gr2.neighbors(i)));
+        }
+    }
+
+    fn graph_map_indices(gr1: UnGraph<(), ()>) -> () {
+        let gr2 = gr1.map(|i, _| i, |e, _| e);
+        assert!(edges_eq!(&gr1, &gr2));
+        for i in gr2.node_indices() {
+            assert_eq!(gr2[i], i);
+        }
+        for e in gr2.edge_indices() {
+            assert_eq!(gr2[e], e);
+        }
+    }
+
     fn stable_di_graph_map_id(gr1: StableDiGraph<usize, usize>) -> () {
         let gr2 = gr1.map(|_, &nw| nw, |_, &ew| ew);
         assert!(nodes_eq!(&gr1, &gr2));
@@ -1313,6 +1878,26 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn bipartite_matching(g: Graph<(), (), Undirected>) -> bool {
+        // keep the edges between even and odd nodes
+        let g = g.filter_map(
+            |_, _| Some(()),
+            |e, _| {
+                let (a, b) = g.edge_endpoints(e).unwrap();
+                if (a.index() + b.index()) % 2 == 1 { Some(()) } else { None }
+            },
+        );
+        let m = maximum_bipartite_matching(&g).unwrap();
+
+        assert!(is_valid_matching(&m), "maximum_bipartite_matching returned an invalid matching");
+        assert!(m.edges().all(|(a, b)| g.contains_edge(a, b)));
+        assert_eq!(m.len(), maximum_matching(&g).len());
+        assert_eq!(m.len(), m.edges().count());
+        true
+    }
+}
+
 quickcheck! {
     // The ranks are probabilities,
     // as such they are positive and they should sum up to 1.
@@ -1376,3 +1961,20 @@ quickcheck! {
         return capacity_constraint && flow_conservation_constraint && max_flow_constaint;
     }
 }
+
+quickcheck! {
+    // The cut weighs as much as the maximum flow, and separates the destination from the source
+    fn min_cut_separates(gr: Graph<(), u32>) -> bool {
+        if gr.node_count() <= 1 {
+            return true;
+        }
+        let gr = gr.map(|_, _| (), |_, &w| u64::from(w));
+        let source = NodeIndex::from(0);
+        let destination = NodeIndex::from(gr.node_count() as u32 / 2);
+        let (max_flow, _) = ford_fulkerson(&gr, source, destination);
+        let (weight, cut) = min_cut(&gr, source, destination);
+        let cut_weight: u64 = cut.iter().map(|&e| gr[e]).sum();
+        let rest = EdgeFiltered::from_fn(&gr, |e| !cut.contains(&e.id()));
+        weight == max_flow && cut_weight == max_flow && !has_path_connecting(&rest, source, destination, None)
+    }
+}
@@ -29,6 +29,84 @@ fn node_indices() {
     assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn indices_match_weights() {
+    let mut g = StableGraph::<_, _>::new();
+    let nodes: Vec<_> = (0..6).map(|i| g.add_node(i)).collect();
+    for i in 0..5 {
+        g.add_edge(nodes[i], nodes[i + 1], 10 * i);
+    }
+    g.remove_node(nodes[0]);
+    g.remove_node(nodes[3]);
+
+    let weights: Vec<_> = g.node_indices().map(|i| g[i]).collect();
+    assert_eq!(weights, vec![1, 2, 4, 5]);
+    let weights: Vec<_> = g.node_indices().rev().map(|i| g[i]).collect();
+    assert_eq!(weights, vec![5, 4, 2, 1]);
+    let weights: Vec<_> = g.edge_indices().map(|i| g[i]).collect();
+    assert_eq!(weights, vec![10, 40]);
+    let weights: Vec<_> = g.edge_indices().rev().map(|i| g[i]).collect();
+    assert_eq!(weights, vec![40, 10]);
+
+    // a Graph reuses the removed indices, but has no vacant ones
+    let mut g = Graph::<(), _>::from_edges(&[(0, 1, 0), (1, 2, 10), (2, 3, 20)]);
+    g.remove_node(n(1));
+    assert_eq!(g.node_indices().count(), g.node_count());
+    assert!(g.node_indices().all(|i| g.node_weight(i).is_some()));
+    let weights: Vec<_> = g.edge_indices().map(|i| g[i]).collect();
+    assert_eq!(weights, vec![20]);
+}
+
+#[test]
+fn remove_keeps_indices() {
+    let mut g = StableGraph::<_, _>::new();
+    let nodes: Vec<_> = (0..5).map(|i| g.add_node(i * 10)).collect();
+    let edges: Vec<_> = (0..4)
+        .map(|i| g.add_edge(nodes[i], nodes[i + 1], i))
+        .collect();
+    let chord = g.add_edge(nodes[0], nodes[4], 100);
+
+    // removing a middle node removes its edges, and nothing else moves
+    assert_eq!(g.remove_node(nodes[2]), Some(20));
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 3);
+    for (i, &nx) in nodes.iter().enumerate() {
+        assert_eq!(
+            g.node_weight(nx).cloned(),
+            if i == 2 { None } else { Some(i * 10) }
+        );
+    }
+    for (i, &ex) in edges.iter().enumerate() {
+        if i == 1 || i == 2 {
+            assert_eq!(g.edge_weight(ex), None);
+        } else {
+            assert_eq!(g.edge_weight(ex), Some(&i));
+            assert_eq!(g.edge_endpoints(ex), Some((nodes[i], nodes[i + 1])));
+        }
+    }
+    assert_eq!(g[chord], 100);
+    assert_eq!(g.edge_endpoints(chord), Some((nodes[0], nodes[4])));
+
+    // iteration skips the vacant slots
+    assert_equal(
+        g.node_indices(),
+        vec![nodes[0], nodes[1], nodes[3], nodes[4]],
+    );
+    assert_equal(g.edge_indices(), vec![edges[0], edges[3], chord]);
+
+    // removing an edge leaves the other edges alone
+    assert_eq!(g.remove_edge(edges[0]), Some(0));
+    assert_eq!(g.edge_weight(edges[3]), Some(&3));
+    assert_eq!(g.edge_endpoints(chord), Some((nodes[0], nodes[4])));
+
+    // the vacant slots are reused
+    assert_eq!(g.add_node(99), nodes[2]);
+    assert_eq!(g.node_bound(), nodes.len());
+    let reused = g.add_edge(nodes[2], nodes[3], 7);
+    assert!(reused == edges[0] || reused == edges[1] || reused == edges[2]);
+    assert_eq!(g[nodes[4]], 40);
+}
+
 #[test]
 fn node_bound() {
     let mut g = StableGraph::<_, ()>::new();
@@ -493,3 +571,30 @@ fn weights_mut_iterator() {
     assert_eq!(gr.node_weights_mut().count(), gr.node_count());
     assert_eq!(gr.edge_weights_mut().count(), gr.edge_count());
 }
+
+#[test]
+fn weights_mut_skip_removed() {
+    let mut g = StableGraph::<_, _>::new();
+    let nodes: Vec<_> = (0..5).map(|i| g.add_node(i)).collect();
+    let edges: Vec<_> = (0..4)
+        .map(|i| g.add_edge(nodes[i], nodes[i + 1], 10 * i))
+        .collect();
+    g.remove_node(nodes[1]);
+    g.remove_edge(edges[3]);
+
+    assert_eq!(g.node_weights().count(), g.node_count());
+    assert_eq!(g.edge_weights().count(), g.edge_count());
+    for w in g.node_weights_mut() {
+        *w *= 100;
+    }
+    for w in g.edge_weights_mut() {
+        *w += 1;
+    }
+    let node_weights: Vec<_> = nodes.iter().map(|&i| g.node_weight(i).cloned()).collect();
+    assert_eq!(
+        node_weights,
+        vec![Some(0), None, Some(200), Some(300), Some(400)]
+    );
+    let edge_weights: Vec<_> = edges.iter().map(|&i| g.edge_weight(i).cloned()).collect();
+    assert_eq!(edge_weights, vec![None, None, Some(21), None]);
+}
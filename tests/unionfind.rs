@@ -1,9 +1,9 @@
 extern crate petgraph;
 extern crate rand;
 
-use petgraph::unionfind::UnionFind;
+use petgraph::unionfind::{RollbackUnionFind, UnionFind};
 use rand::{thread_rng, ChaChaRng, Rng, SeedableRng};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[test]
 fn uf_test() {
@@ -31,6 +31,7 @@ fn uf_test() {
     // check that there are now 3 disjoint sets
     let set = (0..n).map(|i| u.find(i)).collect::<HashSet<_>>();
     assert_eq!(set.len(), 3);
+    assert_eq!(u.set_count(), 3);
 }
 
 #[test]
@@ -73,6 +74,52 @@ fn uf_rand() {
         let br = u.find(b);
         assert_eq!(ar != br, u.union(a, b));
     }
+    let set = (0..n).map(|i| u.find(i)).collect::<HashSet<_>>();
+    assert_eq!(set.len(), u.set_count());
+}
+
+#[test]
+fn representatives_ignore_lookups() {
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    for &by_size in &[false, true] {
+        let new = |n| {
+            if by_size {
+                UnionFind::<u32>::new_by_size(n)
+            } else {
+                UnionFind::new(n)
+            }
+        };
+        let mut plain = new(n);
+        let mut queried = new(n);
+        for _ in 0..2 * n {
+            let a = rng.gen_range(0, n as u32);
+            let b = rng.gen_range(0, n as u32);
+            assert_eq!(plain.union(a, b), queried.union(a, b));
+            // compress some paths of one of them, in a random order
+            for _ in 0..rng.gen_range(0, 8) {
+                let x = rng.gen_range(0, n as u32);
+                match rng.gen_range(0, 4) {
+                    0 => {
+                        queried.find_mut(x);
+                    }
+                    1 => {
+                        queried.find_halving(x);
+                    }
+                    2 => {
+                        queried.equiv_mut(x, a);
+                    }
+                    _ => {
+                        queried.set_size(x);
+                    }
+                }
+            }
+        }
+        queried.labeling();
+        for x in 0..n as u32 {
+            assert_eq!(plain.find(x), queried.find(x));
+        }
+    }
 }
 
 #[test]
@@ -89,6 +136,24 @@ fn uf_u8() {
     }
 }
 
+#[test]
+fn uf_u64() {
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u64>::new(n);
+    let mut v = UnionFind::<usize>::new(n);
+    for _ in 0..n {
+        let a = rng.gen_range(0, n);
+        let b = rng.gen_range(0, n);
+        assert_eq!(u.union(a as u64, b as u64), v.union(a, b));
+    }
+    let labels = u.into_labeling();
+    assert_eq!(
+        labels.iter().map(|&x| x as usize).collect::<Vec<_>>(),
+        v.into_labeling()
+    );
+}
+
 #[test]
 fn labeling() {
     let mut u = UnionFind::<u32>::new(48);
@@ -103,3 +168,606 @@ fn labeling() {
     let v = u.into_labeling();
     assert!(v.iter().all(|x| *x == v[0]));
 }
+
+#[test]
+fn set_size() {
+    let n = 8;
+    let mut u = UnionFind::<u32>::new(n);
+    for i in 0..n as u32 {
+        assert_eq!(u.set_size(i), 1);
+    }
+    // union a chain and check that the sizes add up
+    for i in 0..4 {
+        u.union(i, i + 1);
+        assert_eq!(u.set_size(i + 1), i as usize + 2);
+        assert_eq!(u.set_size(0), i as usize + 2);
+    }
+    u.union(6, 7);
+    assert_eq!(u.set_size(7), 2);
+    u.union(7, 0);
+    for i in 0..5 {
+        assert_eq!(u.set_size(i), 7);
+    }
+    assert_eq!(u.set_size(5), 1);
+    // already in the same set
+    assert!(!u.union(6, 2));
+    assert_eq!(u.set_size(6), 7);
+}
+
+#[test]
+fn set_size_by_size() {
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u32>::new_by_size(n);
+    let mut v = UnionFind::<u32>::new(n);
+    for _ in 0..n {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        assert_eq!(u.union(a, b), v.union(a, b));
+    }
+    for i in 0..n as u32 {
+        let count = (0..n as u32).filter(|&j| u.find(i) == u.find(j)).count();
+        assert_eq!(u.set_size(i), count);
+        assert_eq!(v.set_size(i), count);
+    }
+}
+
+#[test]
+fn by_size_puts_smaller_below() {
+    let mut u = UnionFind::<u32>::new_by_size(5);
+    u.union(1, 2);
+    u.union(1, 3);
+    // {0} is merged below {1, 2, 3} even though it is the first argument
+    u.union(0, 3);
+    assert_eq!(u.find(0), u.find(1));
+    assert_eq!(u.find(0), 1);
+}
+
+#[test]
+#[should_panic]
+fn set_size_out_of_bounds() {
+    let mut u = UnionFind::<u32>::new(4);
+    u.set_size(4);
+}
+
+#[test]
+fn uf_test_with_equiv_mut() {
+    let n = 8;
+    let mut u = UnionFind::<u32>::new(n);
+    for i in 0..n as u32 {
+        assert!(u.equiv_mut(i, i));
+    }
+
+    u.union(0, 1);
+    u.union(1, 3);
+    u.union(4, 7);
+    u.union(3, 7);
+    assert!(u.equiv_mut(0, 7));
+    assert!(u.equiv_mut(4, 1));
+    assert!(!u.equiv_mut(2, 0));
+    for i in 0..n as u32 {
+        for j in 0..n as u32 {
+            assert_eq!(u.equiv_mut(i, j), u.equiv(i, j));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn equiv_out_of_bounds() {
+    let u = UnionFind::<u32>::new(4);
+    u.equiv(4, 4);
+}
+
+#[test]
+#[should_panic]
+fn equiv_mut_out_of_bounds() {
+    let mut u = UnionFind::<u32>::new(4);
+    u.equiv_mut(0, 4);
+}
+
+#[test]
+fn len() {
+    let mut u = UnionFind::<u32>::new(5);
+    assert_eq!(u.len(), 5);
+    assert!(!u.is_empty());
+    u.union(0, 4);
+    assert_eq!(u.len(), 5);
+
+    let u = UnionFind::<u32>::new(0);
+    assert_eq!(u.len(), 0);
+    assert!(u.is_empty());
+}
+
+#[test]
+fn labeling_borrowed() {
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u32>::new(n);
+    for _ in 0..n / 2 {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        u.union(a, b);
+    }
+    let v = u.clone();
+    let labels = u.labeling().to_vec();
+    for i in 0..n {
+        // every label is a root
+        assert_eq!(labels[labels[i] as usize], labels[i]);
+        for j in 0..n {
+            assert_eq!(labels[i] == labels[j], v.equiv(i as u32, j as u32));
+        }
+    }
+    assert_eq!(u.into_labeling(), labels);
+}
+
+#[test]
+fn relabel() {
+    let mut u = UnionFind::<u32>::new(6);
+    assert_eq!(u.relabel(), (vec![0, 1, 2, 3, 4, 5], 6));
+    u.union(5, 1);
+    u.union(4, 2);
+    u.union(2, 0);
+    assert_eq!(u.relabel(), (vec![0, 1, 0, 2, 0, 1], 3));
+    assert_eq!(UnionFind::<u32>::new(0).relabel(), (vec![], 0));
+
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u32>::new(n);
+    for _ in 0..n {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        u.union(a, b);
+    }
+    let (labels, k) = u.relabel();
+    assert_eq!(k, u.set_count());
+    let mut seen = 0;
+    for i in 0..n {
+        // each new set takes the next label
+        assert!(labels[i] <= seen);
+        if labels[i] == seen {
+            seen += 1;
+        }
+        for j in 0..n {
+            assert_eq!(labels[i] == labels[j], u.equiv(i as u32, j as u32));
+        }
+    }
+    assert_eq!(seen, k);
+}
+
+#[test]
+fn canonicalize_min() {
+    let orders: [[(u8, u8); 2]; 4] = [
+        [(1, 3), (3, 4)],
+        [(4, 3), (1, 4)],
+        [(3, 4), (4, 1)],
+        [(4, 1), (3, 1)],
+    ];
+    for unions in &orders {
+        let mut u = UnionFind::<u8>::new(6);
+        for &(a, b) in unions {
+            u.union(a, b);
+        }
+        assert_eq!(u.canonicalize_min(), vec![0, 1, 2, 1, 1, 5]);
+    }
+
+    let mut u = UnionFind::<u8>::new(256);
+    u.union(255, 254);
+    let labels = u.canonicalize_min();
+    assert_eq!(labels[255], 254);
+    assert_eq!(labels[253], 253);
+    assert!(UnionFind::<u32>::new(0).canonicalize_min().is_empty());
+}
+
+#[test]
+fn set_count() {
+    let mut u = UnionFind::<u32>::new(6);
+    assert_eq!(u.set_count(), 6);
+    u.union(0, 1);
+    u.union(2, 3);
+    u.union(1, 0);
+    assert_eq!(u.set_count(), 4);
+    for i in 0..5 {
+        u.union(i, i + 1);
+    }
+    assert_eq!(u.set_count(), 1);
+    assert_eq!(UnionFind::<u32>::new(0).set_count(), 0);
+}
+
+#[test]
+fn roots_by_size() {
+    let mut u = UnionFind::<u32>::new(10);
+    for &(a, b) in &[(0, 1), (1, 2), (3, 4), (5, 4), (6, 3), (7, 6)] {
+        u.union(a, b);
+    }
+    let roots = u.roots_by_size();
+    assert_eq!(
+        roots.iter().map(|&(_, size)| size).collect::<Vec<_>>(),
+        vec![5, 3, 1, 1]
+    );
+    assert_eq!(roots[0].0, u.find(3));
+    assert_eq!(roots[1].0, u.find(0));
+    assert_eq!(roots[2], (8, 1));
+    assert_eq!(roots[3], (9, 1));
+
+    let mut u = UnionFind::<u32>::new_by_size(4);
+    u.union(3, 2);
+    assert_eq!(u.roots_by_size(), vec![(3, 2), (0, 1), (1, 1)]);
+    assert!(UnionFind::<u32>::new(0).roots_by_size().is_empty());
+}
+
+#[test]
+fn push() {
+    let mut u = UnionFind::<u32>::new(2);
+    u.union(0, 1);
+    let x = u.push();
+    assert_eq!(x, 2);
+    assert_eq!(u.len(), 3);
+    assert_eq!(u.find(x), x);
+    assert!(!u.equiv(x, 0));
+    assert!(u.union(x, 0));
+    assert!(u.equiv(x, 1));
+    assert_eq!(u.set_size(x), 3);
+
+    let mut u = UnionFind::<u32>::new(0);
+    for i in 0..10 {
+        assert_eq!(u.push(), i);
+    }
+    assert_eq!(u.set_count(), 10);
+}
+
+#[test]
+fn push_u8() {
+    let mut u = UnionFind::<u8>::new(250);
+    for i in 250..=255 {
+        assert_eq!(u.push(), i);
+    }
+    assert_eq!(u.len(), 256);
+    u.union(255, 0);
+    assert!(u.equiv(0, 255));
+}
+
+#[test]
+#[should_panic(expected = "overflowed")]
+fn push_u8_overflow() {
+    let mut u = UnionFind::<u8>::new(256);
+    u.push();
+}
+
+#[test]
+fn with_capacity() {
+    let mut u = UnionFind::<u32>::with_capacity(16);
+    assert_eq!(u.len(), 0);
+    assert!(u.is_empty());
+    for i in 0..16 {
+        assert_eq!(u.push(), i);
+    }
+    u.reserve(16);
+    for i in 16..32 {
+        assert_eq!(u.push(), i);
+    }
+    assert_eq!(u.len(), 32);
+    assert_eq!(u.set_count(), 32);
+    assert_eq!(UnionFind::<u32>::new(16).len(), 16);
+}
+
+#[test]
+fn find_halving() {
+    let n = 1 << 12;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u32>::new(n);
+    for _ in 0..n {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        let ar = u.find_halving(a);
+        let br = u.find_halving(b);
+        assert_eq!(ar, u.find(a));
+        assert_eq!(ar != br, u.union(a, b));
+    }
+}
+
+#[test]
+fn from_edges() {
+    // a cycle 0 -> 1 -> 2 -> 0, and a separate edge 3 -- 4
+    let mut u = UnionFind::<u32>::from_edges(5, vec![(0, 1), (1, 2), (2, 0), (3, 4)]);
+    assert_eq!(u.set_count(), 2);
+    assert!(u.equiv(0, 2));
+    assert!(u.equiv(4, 3));
+    assert!(!u.equiv(0, 3));
+    assert_eq!(u.set_size(1), 3);
+
+    let u = UnionFind::<u32>::from_edges(3, None);
+    assert_eq!(u.set_count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn from_edges_out_of_bounds() {
+    UnionFind::<u32>::from_edges(3, vec![(0, 1), (2, 3)]);
+}
+
+#[test]
+fn try_find_union() {
+    let mut u = UnionFind::<u32>::new(4);
+    assert_eq!(u.try_union(0, 1), Some(true));
+    assert_eq!(u.try_union(1, 0), Some(false));
+    assert_eq!(u.try_union(0, 4), None);
+    assert_eq!(u.try_union(4, 4), None);
+    assert_eq!(u.try_find(1), Some(u.find(0)));
+    assert_eq!(u.try_find(3), Some(3));
+    assert_eq!(u.try_find(4), None);
+    assert_eq!(u.set_count(), 3);
+}
+
+#[test]
+fn members_and_groups() {
+    let mut u = UnionFind::<u32>::from_edges(7, vec![(5, 3), (0, 5), (2, 4)]);
+    assert_eq!(u.members_of(3).collect::<Vec<_>>(), vec![0, 3, 5]);
+    assert_eq!(u.members_of(4).collect::<Vec<_>>(), vec![2, 4]);
+    assert_eq!(u.members_of(6).collect::<Vec<_>>(), vec![6]);
+    assert_eq!(
+        u.into_groups(),
+        vec![vec![0, 3, 5], vec![1], vec![2, 4], vec![6]]
+    );
+    assert!(UnionFind::<u32>::new(0).into_groups().is_empty());
+}
+
+#[test]
+fn union_with() {
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u32>::new(n);
+    // accumulate the size of each set, kept at its representative
+    let mut sizes = vec![1; n];
+    for _ in 0..n {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        let ar = u.find(a);
+        let br = u.find(b);
+        let mut merge = None;
+        let merged = u.union_with(a, b, |winner, loser| {
+            sizes[winner as usize] += sizes[loser as usize];
+            merge = Some((winner, loser));
+        });
+        assert_eq!(merged, ar != br);
+        if let Some((winner, loser)) = merge {
+            assert!((winner, loser) == (ar, br) || (winner, loser) == (br, ar));
+            assert_eq!(u.find(a), winner);
+        }
+        assert_eq!(sizes[u.find(a) as usize], u.set_size(a));
+    }
+
+    let mut u = UnionFind::<u32>::new(3);
+    let mut calls = 0;
+    u.union_with(0, 1, |_, _| calls += 1);
+    u.union_with(1, 0, |_, _| calls += 1);
+    u.union_with(2, 2, |_, _| calls += 1);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn union_full() {
+    let mut u = UnionFind::<u32>::new(4);
+    assert_eq!(u.union_full(0, 1), Some((0, 1)));
+    assert_eq!(u.union_full(1, 0), None);
+    assert_eq!(u.union_full(3, 3), None);
+    // the set of 0 has the higher rank
+    assert_eq!(u.union_full(2, 1), Some((0, 2)));
+
+    let mut u = UnionFind::<u32>::new_by_size(4);
+    u.union(0, 1);
+    u.union(0, 2);
+    // the set of 0 has more elements
+    assert_eq!(u.union_full(3, 2), Some((0, 3)));
+
+    // rekey a table of sets by their representative
+    let n = 1 << 10;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = UnionFind::<u32>::new_by_size(n);
+    let mut members: HashMap<u32, Vec<u32>> = (0..n as u32).map(|x| (x, vec![x])).collect();
+    for _ in 0..n {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        let ar = u.find(a);
+        let br = u.find(b);
+        match u.union_full(a, b) {
+            None => assert_eq!(ar, br),
+            Some((winner, loser)) => {
+                assert!((winner, loser) == (ar, br) || (winner, loser) == (br, ar));
+                assert_eq!(u.find(loser), winner);
+                let loser_members = members.remove(&loser).unwrap();
+                assert!(members[&winner].len() >= loser_members.len());
+                members.get_mut(&winner).unwrap().extend(loser_members);
+            }
+        }
+    }
+    assert_eq!(members.len(), u.set_count());
+    for (&rep, set) in &members {
+        assert_eq!(u.set_size(rep), set.len());
+        for &x in set {
+            assert_eq!(u.find(x), rep);
+        }
+    }
+}
+
+#[test]
+fn merge() {
+    let n = 1 << 8;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    for &m in &[0, 10, 100, 1000] {
+        let edges: Vec<(u32, u32)> = (0..m)
+            .map(|_| (rng.gen_range(0, n), rng.gen_range(0, n)))
+            .collect();
+        let (left, right) = edges.split_at(m / 3);
+        let mut u = UnionFind::from_edges(n as usize, left.iter().cloned());
+        // the other partition may balance differently
+        let mut v = UnionFind::new_by_size(n as usize);
+        for &(a, b) in right {
+            v.union(a, b);
+        }
+        u.merge(&v);
+        assert_eq!(u, UnionFind::from_edges(n as usize, edges.iter().cloned()));
+        for x in 0..n {
+            assert_eq!(u.set_size(x), u.members_of(x).count());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn merge_length_mismatch() {
+    let mut u = UnionFind::<u32>::new(3);
+    u.merge(&UnionFind::new(4));
+}
+
+#[test]
+fn rollback() {
+    let n = 1 << 8;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut u = RollbackUnionFind::<u32>::new(n);
+    let answers = |u: &RollbackUnionFind<u32>| {
+        (0..n as u32)
+            .flat_map(|a| (0..n as u32).map(move |b| (a, b)))
+            .map(|(a, b)| u.equiv(a, b))
+            .collect::<Vec<_>>()
+    };
+
+    let empty = answers(&u);
+    let t0 = u.snapshot();
+    for _ in 0..n / 2 {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        let ar = u.find(a);
+        let br = u.find(b);
+        assert_eq!(ar != br, u.union(a, b));
+    }
+    let first = answers(&u);
+    let t1 = u.snapshot();
+    for _ in 0..n / 2 {
+        let a = rng.gen_range(0, n as u32);
+        let b = rng.gen_range(0, n as u32);
+        u.union(a, b);
+    }
+
+    u.rollback(t1);
+    assert_eq!(answers(&u), first);
+    // rolling back to the current state is a no-op
+    u.rollback(t1);
+    assert_eq!(answers(&u), first);
+    u.rollback(t0);
+    assert_eq!(answers(&u), empty);
+    assert_eq!(u.snapshot(), t0);
+}
+
+#[test]
+#[should_panic(expected = "invalid snapshot token")]
+fn rollback_invalid_token() {
+    let mut u = RollbackUnionFind::<u32>::new(4);
+    u.union(0, 1);
+    let t = u.snapshot();
+    u.rollback(0);
+    u.rollback(t);
+}
+
+#[test]
+fn default() {
+    #[derive(Default)]
+    struct Components {
+        sets: UnionFind<u32>,
+    }
+    let mut c = Components::default();
+    assert_eq!(c.sets.len(), 0);
+    assert!(c.sets.is_empty());
+    let a = c.sets.push();
+    let b = c.sets.push();
+    c.sets.union(a, b);
+    assert_eq!(c.sets.set_count(), 1);
+}
+
+#[test]
+fn debug() {
+    let mut u = UnionFind::<u32>::new(6);
+    u.union(5, 3);
+    u.union(0, 3);
+    u.union(2, 4);
+    assert_eq!(format!("{:?}", u), "{0, 3, 5} {1} {2, 4}");
+    assert_eq!(format!("{:?}", UnionFind::<u32>::new(0)), "");
+}
+
+#[test]
+fn partition_eq() {
+    let u = UnionFind::<u32>::from_edges(6, vec![(0, 1), (1, 2), (4, 5)]);
+    let v = UnionFind::<u32>::from_edges(6, vec![(5, 4), (2, 0), (2, 1)]);
+    let w = UnionFind::<u32>::from_edges(6, vec![(0, 1), (1, 2), (3, 5)]);
+    assert_eq!(u, v);
+    assert_eq!(v, u);
+    assert_ne!(u, w);
+    // same sets, but a different number of elements
+    let x = UnionFind::<u32>::from_edges(7, vec![(0, 1), (1, 2), (4, 5)]);
+    assert_ne!(u, x);
+    assert_eq!(UnionFind::<u32>::new(3), UnionFind::from_edges(3, None));
+    // union by size and by rank may choose different representatives
+    let mut r = UnionFind::<u32>::new(4);
+    let mut s = UnionFind::<u32>::new_by_size(4);
+    for &(a, b) in &[(1, 2), (1, 3), (0, 3)] {
+        r.union(a, b);
+        s.union(a, b);
+    }
+    assert_eq!(r, s);
+}
+
+#[test]
+fn reset() {
+    let mut u = UnionFind::<u32>::from_edges(6, vec![(0, 1), (1, 2), (4, 5)]);
+    assert_eq!(u.set_count(), 3);
+    u.reset();
+    assert_eq!(u.len(), 6);
+    assert_eq!(u.set_count(), 6);
+    assert_eq!(u, UnionFind::new(6));
+    for i in 0..6 {
+        assert_eq!(u.find(i), i);
+        assert_eq!(u.set_size(i), 1);
+    }
+    assert!(u.union(5, 1));
+    assert_eq!(u.set_size(1), 2);
+}
+
+#[test]
+fn clear() {
+    let mut u = UnionFind::<u32>::from_edges(6, vec![(0, 1), (1, 2), (4, 5)]);
+    u.clear();
+    assert!(u.is_empty());
+    assert_eq!(u.set_count(), 0);
+    assert_eq!(u.try_find(0), None);
+    assert_eq!(u, UnionFind::new(0));
+    for i in 0..3 {
+        assert_eq!(u.push(), i);
+    }
+    assert_eq!(u, UnionFind::new(3));
+    assert!(u.union(0, 2));
+    assert_eq!(u.set_size(2), 2);
+    assert_eq!(u.set_size(1), 1);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_from_edges() {
+    let n = 1000;
+    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    for &m in &[0, 1, 10, 500, 5000] {
+        let edges: Vec<(u32, u32)> = (0..m)
+            .map(|_| (rng.gen_range(0, n), rng.gen_range(0, n)))
+            .collect();
+        let par = UnionFind::par_from_edges(n as usize, &edges);
+        let seq = UnionFind::from_edges(n as usize, edges.iter().cloned());
+        assert_eq!(par, seq);
+        assert_eq!(par.set_count(), seq.set_count());
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+#[should_panic]
+fn par_from_edges_out_of_bounds() {
+    UnionFind::<u32>::par_from_edges(3, &[(0, 1), (2, 3)]);
+}